@@ -0,0 +1,75 @@
+//! Compares checked and unchecked unwrapping across payload sizes.
+//!
+//! Run with `cargo bench`.
+
+// `unsafe_unwrap()` is exercised even when the `strict` feature is enabled.
+#![allow(deprecated)]
+
+#[macro_use]
+extern crate criterion;
+extern crate unsafe_unwrap;
+
+use std::hint::black_box;
+use std::num::NonZeroU32;
+
+use criterion::{BenchmarkId, Criterion};
+use unsafe_unwrap::{UnsafeUnwrap, UnsafeUnwrapExt};
+
+const ITERS: usize = 1000;
+
+macro_rules! bench_payload {
+    ($c:expr, $name:expr, $value:expr) => {{
+        let mut group = $c.benchmark_group($name);
+        let opt = Some($value);
+
+        group.bench_function(BenchmarkId::new("by_value", "unwrap"), |b| b.iter(|| {
+            for _ in 0..ITERS {
+                black_box(black_box(opt).unwrap());
+            }
+        }));
+        group.bench_function(BenchmarkId::new("by_value", "unwrap_unchecked"), |b| b.iter(|| {
+            for _ in 0..ITERS {
+                black_box(unsafe { black_box(opt).unwrap_unchecked() });
+            }
+        }));
+        group.bench_function(BenchmarkId::new("by_value", "unsafe_unwrap"), |b| b.iter(|| {
+            for _ in 0..ITERS {
+                black_box(unsafe { black_box(opt).unsafe_unwrap() });
+            }
+        }));
+
+        group.bench_function(BenchmarkId::new("by_ref", "unwrap"), |b| b.iter(|| {
+            for _ in 0..ITERS {
+                black_box(black_box(&opt).as_ref().unwrap());
+            }
+        }));
+        group.bench_function(BenchmarkId::new("by_ref", "unsafe_unwrap"), |b| b.iter(|| {
+            for _ in 0..ITERS {
+                black_box(unsafe { black_box(&opt).as_ref().unsafe_unwrap() });
+            }
+        }));
+        group.bench_function(BenchmarkId::new("by_ref", "unsafe_unwrap_ref"), |b| b.iter(|| {
+            for _ in 0..ITERS {
+                black_box(unsafe { black_box(&opt).unsafe_unwrap_ref() });
+            }
+        }));
+
+        group.finish();
+    }};
+}
+
+fn payloads(c: &mut Criterion) {
+    bench_payload!(c, "u8", 1u8);
+    bench_payload!(c, "u64", 1u64);
+    bench_payload!(c, "[u8; 64]", [1u8; 64]);
+    bench_payload!(c, "[u8; 1024]", [1u8; 1024]);
+}
+
+fn niches(c: &mut Criterion) {
+    static VALUE: u64 = 1;
+    bench_payload!(c, "NonZeroU32", NonZeroU32::new(1).unwrap());
+    bench_payload!(c, "&u64", &VALUE);
+}
+
+criterion_group!(benches, payloads, niches);
+criterion_main!(benches);
@@ -0,0 +1,297 @@
+//! Procedural macros for [`unsafe_unwrap`](https://docs.rs/unsafe_unwrap).
+//!
+//! These are re-exported by `unsafe_unwrap` when its `macros` feature is
+//! enabled and should not be depended on directly.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::spanned::Spanned;
+use syn::visit_mut::VisitMut;
+use syn::{Data, DeriveInput, Error, Expr, Fields, Ident, ItemFn, Lit, ReturnType, Type};
+
+const REPRS: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize",
+    "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// Derives `UnsafeFromPrimitive` for a fieldless enum with an integer
+/// `#[repr]`.
+#[proc_macro_derive(UnsafeFromPrimitive)]
+pub fn derive_unsafe_from_primitive(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    match unsafe_from_primitive(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn unsafe_from_primitive(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let variants = match input.data {
+        Data::Enum(ref data) => &data.variants,
+        _ => return Err(Error::new_spanned(
+            input,
+            "`UnsafeFromPrimitive` can only be derived for enums",
+        )),
+    };
+
+    for variant in variants {
+        if let Fields::Unit = variant.fields {
+            continue;
+        }
+        return Err(Error::new_spanned(
+            variant,
+            "`UnsafeFromPrimitive` requires all variants to be fieldless",
+        ));
+    }
+
+    let repr = repr_of(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+    let variants = variants.iter().map(|v| &v.ident);
+
+    Ok(quote! {
+        impl #impl_generics ::unsafe_unwrap::UnsafeFromPrimitive
+            for #name #ty_generics #where_clause
+        {
+            type Primitive = #repr;
+
+            #[inline]
+            unsafe fn from_discriminant_unchecked(value: #repr) -> Self {
+                ::unsafe_unwrap::__private::assume(
+                    false #(|| value == #name::#variants as #repr)*
+                );
+                ::unsafe_unwrap::__private::transmute::<#repr, Self>(value)
+            }
+        }
+    })
+}
+
+/// Returns the integer type named by the enum's `#[repr]` attribute.
+fn repr_of(input: &DeriveInput) -> syn::Result<Ident> {
+    let mut repr = None;
+    for attr in &input.attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                if REPRS.iter().any(|r| ident == r) {
+                    repr = Some(ident.clone());
+                }
+            }
+            // Skip arguments of other hints, such as `align(4)`.
+            if meta.input.peek(syn::token::Paren) {
+                let _args;
+                syn::parenthesized!(_args in meta.input);
+            }
+            Ok(())
+        })?;
+    }
+    repr.ok_or_else(|| Error::new_spanned(
+        &input.ident,
+        "`UnsafeFromPrimitive` requires an integer `#[repr]`, \
+         such as `#[repr(u8)]`",
+    ))
+}
+
+/// Checks a precondition on entry to an `unsafe fn` in debug builds and
+/// assumes it in optimized builds.
+///
+/// The condition becomes part of the function's safety contract: callers
+/// must ensure that it holds. Only `unsafe fn` is accepted, since assuming a
+/// condition that safe code can violate is undefined behavior.
+///
+/// ```ignore
+/// #[requires(i < v.len())]
+/// unsafe fn get(v: &[u8], i: usize) -> u8 {
+///     v[i] // The bounds check is removed in optimized builds.
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn requires(args: TokenStream, input: TokenStream) -> TokenStream {
+    let cond = syn::parse_macro_input!(args as Expr);
+    let item = syn::parse_macro_input!(input as ItemFn);
+    match contract_fn(item, "requires") {
+        Ok(mut item) => {
+            let block = &item.block;
+            item.block = syn::parse_quote!({
+                let __cond: bool = #cond;
+                unsafe { ::unsafe_unwrap::__private::assume(__cond) }
+                #block
+            });
+            quote!(#item).into()
+        }
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Checks a postcondition on exit from an `unsafe fn` in debug builds and
+/// assumes it in optimized builds.
+///
+/// The returned value is bound to `ret` within the condition. The function
+/// body must ensure that the condition holds whenever its preconditions do.
+/// Only `unsafe fn` is accepted, as with [`requires`](macro@requires).
+///
+/// ```ignore
+/// #[ensures(ret <= v.len())]
+/// unsafe fn split_point(v: &[u8]) -> usize {
+///     v.iter().position(|&b| b == 0).unwrap_or(v.len())
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn ensures(args: TokenStream, input: TokenStream) -> TokenStream {
+    let cond = syn::parse_macro_input!(args as Expr);
+    let item = syn::parse_macro_input!(input as ItemFn);
+    match contract_fn(item, "ensures") {
+        Ok(mut item) => {
+            let block = &item.block;
+            // The body runs in a closure so that `return` and `?` still pass
+            // through the postcondition.
+            let (ty, call) = match item.sig.output {
+                ReturnType::Default => (quote!(()), quote!((|| #block)())),
+                ReturnType::Type(_, ref ty) => match **ty {
+                    // Closures cannot name an `impl Trait` return type.
+                    Type::ImplTrait(_) => (quote!(_), quote!((|| #block)())),
+                    ref ty => (quote!(#ty), quote!((|| -> #ty #block)())),
+                },
+            };
+            item.block = syn::parse_quote!({
+                #[allow(clippy::redundant_closure_call)]
+                let ret: #ty = #call;
+                let __cond: bool = #cond;
+                unsafe { ::unsafe_unwrap::__private::assume(__cond) }
+                ret
+            });
+            quote!(#item).into()
+        }
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Rewrites `.unwrap()` and `.expect("...")` within an `unsafe fn` into this
+/// crate's unchecked equivalents, which are checked in debug builds.
+///
+/// With `#[assume_unwraps(question_mark)]`, every `?` is also assumed not to
+/// short-circuit. Calls within macro invocations and nested items are left
+/// as-is, as are `expect` calls whose message is not a string literal. Only
+/// `unsafe fn` is accepted, since callers inherit the assumptions.
+///
+/// ```ignore
+/// #[assume_unwraps]
+/// unsafe fn sum_first(rows: &[Vec<u32>]) -> u32 {
+///     rows.iter().map(|r| *r.first().expect("rows are non-empty")).sum()
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn assume_unwraps(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut rewriter = UnwrapRewriter { question_mark: false };
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("question_mark") {
+            rewriter.question_mark = true;
+            Ok(())
+        } else {
+            Err(meta.error("expected `question_mark`"))
+        }
+    });
+    syn::parse_macro_input!(args with parser);
+    let item = syn::parse_macro_input!(input as ItemFn);
+    match contract_fn(item, "assume_unwraps") {
+        Ok(mut item) => {
+            rewriter.visit_block_mut(&mut item.block);
+            quote!(#item).into()
+        }
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+struct UnwrapRewriter {
+    question_mark: bool,
+}
+
+impl UnwrapRewriter {
+    fn unchecked(expr: &Expr, reason: &Lit) -> Expr {
+        syn::parse_quote_spanned! { expr.span() =>
+            unsafe {
+                ::unsafe_unwrap::UnsafeUnwrap::unsafe_unwrap_because(
+                    #expr, #reason,
+                )
+            }
+        }
+    }
+}
+
+impl VisitMut for UnwrapRewriter {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        syn::visit_mut::visit_expr_mut(self, expr);
+        let rewritten = match *expr {
+            Expr::MethodCall(ref call) if call.turbofish.is_none() => {
+                let method = call.method.to_string();
+                match (method.as_str(), call.args.len()) {
+                    ("unwrap", 0) => Some(Self::unchecked(
+                        &call.receiver,
+                        &syn::parse_quote!("`unwrap()` assumed to succeed"),
+                    )),
+                    ("expect", 1) => match call.args[0] {
+                        Expr::Lit(ref lit) => match lit.lit {
+                            Lit::Str(_) => Some(Self::unchecked(
+                                &call.receiver,
+                                &lit.lit,
+                            )),
+                            _ => None,
+                        },
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            }
+            Expr::Try(ref try_expr) if self.question_mark => {
+                Some(Self::unchecked(
+                    &try_expr.expr,
+                    &syn::parse_quote!("`?` assumed not to short-circuit"),
+                ))
+            }
+            _ => None,
+        };
+        if let Some(rewritten) = rewritten {
+            *expr = rewritten;
+        }
+    }
+
+    // Nested items are not part of the annotated function.
+    fn visit_item_mut(&mut self, _: &mut syn::Item) {}
+}
+
+/// Validates that an attribute is applied to a plain `unsafe fn`.
+fn contract_fn(item: ItemFn, attr: &str) -> syn::Result<ItemFn> {
+    let sig = &item.sig;
+    if sig.unsafety.is_none() {
+        return Err(Error::new_spanned(
+            sig.fn_token,
+            format!(
+                "`#[{}]` can only be applied to an `unsafe fn`, since its \
+                 condition is assumed in optimized builds",
+                attr,
+            ),
+        ));
+    }
+    if let Some(ref token) = sig.constness {
+        return Err(Error::new_spanned(
+            token,
+            format!("`#[{}]` does not support `const fn`", attr),
+        ));
+    }
+    if let Some(ref token) = sig.asyncness {
+        return Err(Error::new_spanned(
+            token,
+            format!("`#[{}]` does not support `async fn`", attr),
+        ));
+    }
+    Ok(item)
+}
@@ -0,0 +1,925 @@
+//! Unchecked operations on collections.
+//!
+//! This module requires the `collections` feature, which implies `alloc`. The
+//! hash map and set traits are implemented for the standard collections with
+//! the `std` feature, and for `hashbrown` collections with the `hashbrown`
+//! feature.
+
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BinaryHeap, VecDeque};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+#[cfg(feature = "std")]
+use core::hash::BuildHasher;
+use core::hash::Hash;
+use core::ptr;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "std")]
+use std::collections::hash_map::{Entry, OccupiedEntry};
+
+use {UnsafeUnwrap, assume};
+#[cfg(feature = "std")]
+use unreachable;
+
+/// Unchecked operations on `Vec<T>` that assume preconditions which the
+/// standard methods would otherwise check.
+pub trait UnsafeVec<T> {
+    /// Appends `value` without checking that there is spare capacity.
+    ///
+    /// This removes the growth branch from `push`, which is useful when
+    /// `reserve` was called once ahead of many pushes.
+    ///
+    /// # Safety
+    ///
+    /// `len()` must be less than `capacity()`. This is checked in debug
+    /// builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::collections::UnsafeVec;
+    ///
+    /// let mut v = Vec::with_capacity(3);
+    /// for i in 0..3 {
+    ///     unsafe { v.push_unchecked(i) };
+    /// }
+    /// assert_eq!(v, [0, 1, 2]);
+    /// ```
+    unsafe fn push_unchecked(&mut self, value: T);
+
+    /// Clones and appends all elements of `other` without checking that
+    /// there is spare capacity.
+    ///
+    /// If a call to `clone` panics, the elements appended so far are leaked.
+    ///
+    /// # Safety
+    ///
+    /// `capacity() - len()` must be at least `other.len()`. This is checked
+    /// in debug builds.
+    unsafe fn extend_from_slice_unchecked(&mut self, other: &[T])
+        where T: Clone;
+
+    /// Converts `self` into a vector of `N`-element arrays without checking
+    /// that its length is a multiple of `N`.
+    ///
+    /// This reallocates if the vector has spare capacity. `N` must not be
+    /// zero, which is checked at compile time.
+    ///
+    /// # Safety
+    ///
+    /// `len()` must be a multiple of `N`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::collections::UnsafeVec;
+    ///
+    /// let samples = vec![1i16, -1, 2, -2];
+    /// let frames = unsafe { samples.into_chunks_unchecked::<2>() };
+    /// assert_eq!(frames, [[1, -1], [2, -2]]);
+    /// ```
+    unsafe fn into_chunks_unchecked<const N: usize>(self) -> Vec<[T; N]>;
+
+    /// Removes and returns the element at `index` without bounds checking,
+    /// shifting all elements after it to the left.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than `len()`. This is checked in debug builds.
+    unsafe fn remove_unchecked(&mut self, index: usize) -> T;
+
+    /// Inserts `value` at `index` without bounds checking or spare capacity,
+    /// shifting all elements after it to the right.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be at most `len()`, and `len()` must be less than
+    /// `capacity()`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::collections::UnsafeVec;
+    ///
+    /// let mut v = Vec::with_capacity(4);
+    /// v.extend_from_slice(b"act");
+    /// unsafe {
+    ///     v.insert_unchecked(1, b'r');
+    ///     assert_eq!(v.remove_unchecked(0), b'a');
+    /// }
+    /// assert_eq!(v, b"rct");
+    /// ```
+    unsafe fn insert_unchecked(&mut self, index: usize, value: T);
+}
+
+impl<T> UnsafeVec<T> for Vec<T> {
+    #[inline]
+    unsafe fn push_unchecked(&mut self, value: T) {
+        let len = self.len();
+        assume(len < self.capacity());
+        ptr::write(self.as_mut_ptr().add(len), value);
+        self.set_len(len + 1);
+    }
+
+    #[inline]
+    unsafe fn extend_from_slice_unchecked(&mut self, other: &[T])
+        where T: Clone
+    {
+        let len = self.len();
+        assume(self.capacity() - len >= other.len());
+        let dst = self.as_mut_ptr().add(len);
+        for (i, value) in other.iter().enumerate() {
+            ptr::write(dst.add(i), value.clone());
+        }
+        self.set_len(len + other.len());
+    }
+
+    #[inline]
+    unsafe fn into_chunks_unchecked<const N: usize>(self) -> Vec<[T; N]> {
+        const { assert!(N != 0, "chunk size must be non-zero") };
+        let len = self.len();
+        assume(len.is_multiple_of(N));
+        // Boxing the slice makes the allocation exactly `len` elements long,
+        // which is also the size of `len / N` arrays.
+        let ptr = Box::into_raw(self.into_boxed_slice()) as *mut [T; N];
+        let chunks = ptr::slice_from_raw_parts_mut(ptr, len / N);
+        Box::from_raw(chunks).into_vec()
+    }
+
+    #[inline]
+    unsafe fn remove_unchecked(&mut self, index: usize) -> T {
+        let len = self.len();
+        assume(index < len);
+        let ptr = self.as_mut_ptr().add(index);
+        let value = ptr::read(ptr);
+        ptr::copy(ptr.add(1), ptr, len - index - 1);
+        self.set_len(len - 1);
+        value
+    }
+
+    #[inline]
+    unsafe fn insert_unchecked(&mut self, index: usize, value: T) {
+        let len = self.len();
+        assume(index <= len && len < self.capacity());
+        let ptr = self.as_mut_ptr().add(index);
+        ptr::copy(ptr, ptr.add(1), len - index);
+        ptr::write(ptr, value);
+        self.set_len(len + 1);
+    }
+}
+
+/// Converts `vec` into an array without checking that its length is `N`.
+///
+/// Unlike `TryInto`, this has no failure path that hands the vector back.
+///
+/// # Safety
+///
+/// `vec.len()` must equal `N`. This is checked in debug builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::collections::vec_into_array_unchecked;
+///
+/// let digest: Vec<u8> = (0..32).collect();
+/// let digest: [u8; 32] = unsafe { vec_into_array_unchecked(digest) };
+/// assert_eq!(digest[31], 31);
+/// ```
+#[inline]
+pub unsafe fn vec_into_array_unchecked<const N: usize, T>(
+    mut vec: Vec<T>,
+) -> [T; N] {
+    assume(vec.len() == N);
+    // The elements are moved out, so only the allocation is left to free.
+    vec.set_len(0);
+    ptr::read(vec.as_ptr() as *const [T; N])
+}
+
+/// Converts `slice` into a boxed array without checking that its length is
+/// `N`.
+///
+/// This reuses the allocation of `slice`.
+///
+/// # Safety
+///
+/// `slice.len()` must equal `N`. This is checked in debug builds.
+#[inline]
+pub unsafe fn boxed_slice_into_array_unchecked<const N: usize, T>(
+    slice: Box<[T]>,
+) -> Box<[T; N]> {
+    assume(slice.len() == N);
+    Box::from_raw(Box::into_raw(slice) as *mut [T; N])
+}
+
+/// Unchecked editing of `String` where character boundaries or spare
+/// capacity are already known.
+pub trait UnsafeString {
+    /// Removes the last character without checking that there is one.
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be empty. This is checked in debug builds.
+    unsafe fn pop_unchecked(&mut self) -> char;
+
+    /// Removes the character at byte index `idx` without checking that it
+    /// starts one.
+    ///
+    /// # Safety
+    ///
+    /// `idx` must be less than `len()` and lie on a character boundary. This
+    /// is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::collections::UnsafeString;
+    ///
+    /// let mut s = String::from("na\u{ef}ve");
+    /// assert_eq!(unsafe { s.remove_unchecked(2) }, '\u{ef}');
+    /// unsafe { s.insert_unchecked(2, 'i') };
+    /// assert_eq!(s, "naive");
+    /// ```
+    unsafe fn remove_unchecked(&mut self, idx: usize) -> char;
+
+    /// Inserts `ch` at byte index `idx` without checking that it lies on a
+    /// character boundary.
+    ///
+    /// # Safety
+    ///
+    /// `idx` must be at most `len()` and lie on a character boundary. This is
+    /// checked in debug builds.
+    unsafe fn insert_unchecked(&mut self, idx: usize, ch: char);
+
+    /// Appends `ch` without checking that there is spare capacity.
+    ///
+    /// # Safety
+    ///
+    /// `capacity() - len()` must be at least `ch.len_utf8()`. This is checked
+    /// in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::collections::UnsafeString;
+    ///
+    /// let mut s = String::with_capacity(8);
+    /// unsafe {
+    ///     s.push_str_unchecked("-42");
+    ///     s.push_unchecked('\u{b0}');
+    /// }
+    /// assert_eq!(s, "-42\u{b0}");
+    /// ```
+    unsafe fn push_unchecked(&mut self, ch: char);
+
+    /// Appends `string` without checking that there is spare capacity.
+    ///
+    /// # Safety
+    ///
+    /// `capacity() - len()` must be at least `string.len()`. This is checked
+    /// in debug builds.
+    unsafe fn push_str_unchecked(&mut self, string: &str);
+}
+
+impl UnsafeString for String {
+    #[inline]
+    unsafe fn pop_unchecked(&mut self) -> char {
+        self.pop().unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn remove_unchecked(&mut self, idx: usize) -> char {
+        let len = self.len();
+        assume(idx < len && self.is_char_boundary(idx));
+        let ch = self.get_unchecked(idx..).chars().next().unsafe_unwrap();
+        let next = idx + ch.len_utf8();
+        let v = self.as_mut_vec();
+        ptr::copy(v.as_ptr().add(next), v.as_mut_ptr().add(idx), len - next);
+        v.set_len(len - (next - idx));
+        ch
+    }
+
+    #[inline]
+    unsafe fn insert_unchecked(&mut self, idx: usize, ch: char) {
+        assume(self.is_char_boundary(idx));
+        let mut buf = [0; 4];
+        let bytes = ch.encode_utf8(&mut buf).as_bytes();
+        let len = self.len();
+        let v = self.as_mut_vec();
+        v.reserve(bytes.len());
+        let dst = v.as_mut_ptr().add(idx);
+        ptr::copy(dst, dst.add(bytes.len()), len - idx);
+        ptr::copy_nonoverlapping(bytes.as_ptr(), dst, bytes.len());
+        v.set_len(len + bytes.len());
+    }
+
+    #[inline]
+    unsafe fn push_unchecked(&mut self, ch: char) {
+        let mut buf = [0; 4];
+        self.push_str_unchecked(ch.encode_utf8(&mut buf));
+    }
+
+    #[inline]
+    unsafe fn push_str_unchecked(&mut self, string: &str) {
+        let len = self.len();
+        assume(self.capacity() - len >= string.len());
+        let v = self.as_mut_vec();
+        let dst = v.as_mut_ptr().add(len);
+        ptr::copy_nonoverlapping(string.as_ptr(), dst, string.len());
+        v.set_len(len + string.len());
+    }
+}
+
+/// Unchecked access to the contents of a `VecDeque` whose ring buffer is
+/// known not to wrap around.
+pub trait UnsafeVecDeque<T> {
+    /// Returns the contents as one slice, assuming that they are contiguous.
+    ///
+    /// Unlike `make_contiguous`, this never moves elements, and unlike
+    /// `as_slices`, there is no second slice to handle.
+    ///
+    /// # Safety
+    ///
+    /// The second slice of `as_slices()` must be empty. This is checked in
+    /// debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use unsafe_unwrap::collections::UnsafeVecDeque;
+    ///
+    /// let mut window = VecDeque::with_capacity(4);
+    /// window.extend([1, 2, 3, 4].iter().copied());
+    /// window.pop_front();
+    /// assert_eq!(unsafe { window.assume_contiguous() }, [2, 3, 4]);
+    /// ```
+    unsafe fn assume_contiguous(&self) -> &[T];
+
+    /// Returns the contents as one mutable slice, assuming that they are
+    /// contiguous.
+    ///
+    /// # Safety
+    ///
+    /// The second slice of `as_slices()` must be empty. This is checked in
+    /// debug builds.
+    unsafe fn assume_contiguous_mut(&mut self) -> &mut [T];
+}
+
+impl<T> UnsafeVecDeque<T> for VecDeque<T> {
+    #[inline]
+    unsafe fn assume_contiguous(&self) -> &[T] {
+        let (front, back) = self.as_slices();
+        assume(back.is_empty());
+        front
+    }
+
+    #[inline]
+    unsafe fn assume_contiguous_mut(&mut self) -> &mut [T] {
+        let (front, back) = self.as_mut_slices();
+        assume(back.is_empty());
+        front
+    }
+}
+
+/// Unchecked access to the greatest element of a `BinaryHeap` that is known
+/// to be non-empty.
+pub trait UnsafeBinaryHeap<T> {
+    /// Returns the greatest element, assuming that there is one.
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be empty. This is checked in debug builds.
+    unsafe fn peek_unchecked(&self) -> &T;
+
+    /// Removes and returns the greatest element, assuming that there is one.
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be empty. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::cmp::Reverse;
+    /// use std::collections::BinaryHeap;
+    /// use unsafe_unwrap::collections::UnsafeBinaryHeap;
+    ///
+    /// let mut events = BinaryHeap::new();
+    /// events.push(Reverse(20));
+    /// events.push(Reverse(5));
+    /// while !events.is_empty() {
+    ///     let Reverse(time) = unsafe { events.pop_unchecked() };
+    ///     if time < 10 {
+    ///         events.push(Reverse(time * 3));
+    ///     }
+    /// }
+    /// ```
+    unsafe fn pop_unchecked(&mut self) -> T;
+}
+
+impl<T: Ord> UnsafeBinaryHeap<T> for BinaryHeap<T> {
+    #[inline]
+    unsafe fn peek_unchecked(&self) -> &T {
+        self.peek().unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn pop_unchecked(&mut self) -> T {
+        self.pop().unsafe_unwrap()
+    }
+}
+
+/// Unchecked operations on hash maps whose keys are known to be present.
+///
+/// The entry type is a generic associated type so that maps other than the
+/// standard `HashMap` can return their own.
+pub trait UnsafeHashMap<K, V> {
+    /// The occupied entry type of the map.
+    type OccupiedEntry<'a> where Self: 'a;
+
+    /// Returns a reference to the value for `key`, assuming that it is
+    /// present.
+    ///
+    /// # Safety
+    ///
+    /// `self` must contain `key`. This is checked in debug builds.
+    unsafe fn get_existing<Q>(&self, key: &Q) -> &V
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized;
+
+    /// Returns a mutable reference to the value for `key`, assuming that it
+    /// is present.
+    ///
+    /// # Safety
+    ///
+    /// `self` must contain `key`. This is checked in debug builds.
+    unsafe fn get_existing_mut<Q>(&mut self, key: &Q) -> &mut V
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized;
+
+    /// Returns the value for `key`, assuming that it is present.
+    ///
+    /// Unlike matching on `entry(key)`, this emits no code for the vacant
+    /// case in optimized builds.
+    ///
+    /// # Safety
+    ///
+    /// `self` must contain `key`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")] {
+    /// use std::collections::HashMap;
+    /// use unsafe_unwrap::collections::UnsafeHashMap;
+    ///
+    /// let mut counts = HashMap::new();
+    /// counts.insert("a", 0);
+    /// counts.insert("b", 0);
+    /// for word in ["a", "b", "a"].iter() {
+    ///     unsafe { *counts.entry_existing(word) += 1 };
+    /// }
+    /// assert_eq!(counts["a"], 2);
+    /// # }
+    /// ```
+    unsafe fn entry_existing(&mut self, key: K) -> &mut V;
+
+    /// Returns the occupied entry for `key`, assuming that it is present.
+    ///
+    /// # Safety
+    ///
+    /// `self` must contain `key`. This is checked in debug builds.
+    unsafe fn occupied_entry_unchecked(
+        &mut self,
+        key: K,
+    ) -> Self::OccupiedEntry<'_>;
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> UnsafeHashMap<K, V> for HashMap<K, V, S>
+    where K: Eq + Hash, S: BuildHasher
+{
+    type OccupiedEntry<'a> = OccupiedEntry<'a, K, V> where Self: 'a;
+
+    #[inline]
+    unsafe fn get_existing<Q>(&self, key: &Q) -> &V
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        self.get(key).unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn get_existing_mut<Q>(&mut self, key: &Q) -> &mut V
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        self.get_mut(key).unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn entry_existing(&mut self, key: K) -> &mut V {
+        self.occupied_entry_unchecked(key).into_mut()
+    }
+
+    #[inline]
+    unsafe fn occupied_entry_unchecked(
+        &mut self,
+        key: K,
+    ) -> OccupiedEntry<'_, K, V> {
+        match self.entry(key) {
+            Entry::Occupied(entry) => entry,
+            Entry::Vacant(_) => unreachable(),
+        }
+    }
+}
+
+/// Unchecked operations on hash sets whose values are known to be present.
+pub trait UnsafeHashSet<T> {
+    /// Returns a reference to the stored value equal to `value`, assuming
+    /// that there is one.
+    ///
+    /// # Safety
+    ///
+    /// `self` must contain `value`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")] {
+    /// use std::collections::HashSet;
+    /// use unsafe_unwrap::collections::UnsafeHashSet;
+    ///
+    /// let mut interned = HashSet::new();
+    /// interned.insert(String::from("GET"));
+    /// let method: &String = unsafe { interned.get_existing("GET") };
+    /// assert_eq!(method, "GET");
+    /// # }
+    /// ```
+    unsafe fn get_existing<Q>(&self, value: &Q) -> &T
+        where T: Borrow<Q>, Q: Hash + Eq + ?Sized;
+}
+
+#[cfg(feature = "std")]
+impl<T, S> UnsafeHashSet<T> for HashSet<T, S>
+    where T: Eq + Hash, S: BuildHasher
+{
+    #[inline]
+    unsafe fn get_existing<Q>(&self, value: &Q) -> &T
+        where T: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        self.get(value).unsafe_unwrap()
+    }
+}
+
+/// Unchecked access to the boundaries of a `BTreeMap` that is known to be
+/// non-empty.
+pub trait UnsafeBTreeMap<K, V> {
+    /// Returns the first key-value pair, assuming that there is one.
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be empty. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use unsafe_unwrap::collections::UnsafeBTreeMap;
+    ///
+    /// let mut deadlines = BTreeMap::new();
+    /// deadlines.insert(30, "flush");
+    /// deadlines.insert(10, "poll");
+    /// assert_eq!(unsafe { deadlines.pop_first_unchecked() }, (10, "poll"));
+    /// ```
+    unsafe fn first_key_value_unchecked(&self) -> (&K, &V);
+
+    /// Returns the last key-value pair, assuming that there is one.
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be empty. This is checked in debug builds.
+    unsafe fn last_key_value_unchecked(&self) -> (&K, &V);
+
+    /// Removes and returns the first key-value pair, assuming that there is
+    /// one.
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be empty. This is checked in debug builds.
+    unsafe fn pop_first_unchecked(&mut self) -> (K, V);
+
+    /// Removes and returns the last key-value pair, assuming that there is
+    /// one.
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be empty. This is checked in debug builds.
+    unsafe fn pop_last_unchecked(&mut self) -> (K, V);
+}
+
+impl<K: Ord, V> UnsafeBTreeMap<K, V> for BTreeMap<K, V> {
+    #[inline]
+    unsafe fn first_key_value_unchecked(&self) -> (&K, &V) {
+        self.first_key_value().unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn last_key_value_unchecked(&self) -> (&K, &V) {
+        self.last_key_value().unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn pop_first_unchecked(&mut self) -> (K, V) {
+        self.pop_first().unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn pop_last_unchecked(&mut self) -> (K, V) {
+        self.pop_last().unsafe_unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec_push_success() {
+        let mut v = Vec::with_capacity(2);
+        unsafe {
+            v.push_unchecked(1);
+            v.push_unchecked(2);
+        }
+        assert_eq!(v, [1, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn vec_push_failure() {
+        let mut v = Vec::new();
+        unsafe { v.push_unchecked(1) };
+    }
+
+    #[test]
+    fn vec_extend_success() {
+        let mut v = Vec::with_capacity(5);
+        v.push(0);
+        unsafe { v.extend_from_slice_unchecked(&[1, 2, 3, 4]) };
+        assert_eq!(v, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn vec_extend_failure() {
+        let mut v = Vec::from([1]);
+        v.shrink_to_fit();
+        assert_eq!(v.capacity(), v.len());
+        unsafe { v.extend_from_slice_unchecked(&[2]) };
+    }
+
+    #[test]
+    fn vec_into_chunks_success() {
+        let mut v = Vec::with_capacity(8);
+        v.extend_from_slice(&[1, 2, 3, 4, 5, 6]);
+        let chunks = unsafe { v.into_chunks_unchecked::<3>() };
+        assert_eq!(chunks, [[1, 2, 3], [4, 5, 6]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn vec_into_chunks_failure() {
+        let mut v = Vec::new();
+        v.extend_from_slice(&[1, 2, 3]);
+        unsafe { v.into_chunks_unchecked::<2>() };
+    }
+
+    #[test]
+    fn vec_remove_insert_success() {
+        let mut v = Vec::with_capacity(4);
+        v.extend_from_slice(&[1, 2, 3]);
+        unsafe {
+            assert_eq!(v.remove_unchecked(1), 2);
+            v.insert_unchecked(2, 4);
+            v.insert_unchecked(0, 0);
+        }
+        assert_eq!(v, [0, 1, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn vec_remove_failure() {
+        let mut v = Vec::from([1]);
+        unsafe { v.remove_unchecked(1) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn vec_insert_failure() {
+        let mut v = Vec::from([1]);
+        v.shrink_to_fit();
+        assert_eq!(v.capacity(), v.len());
+        unsafe { v.insert_unchecked(0, 0) };
+    }
+
+    #[test]
+    fn string_edit_success() {
+        let mut s = String::from("h\u{e9}llo");
+        unsafe {
+            assert_eq!(s.pop_unchecked(), 'o');
+            assert_eq!(s.remove_unchecked(1), '\u{e9}');
+            s.insert_unchecked(1, '\u{1F600}');
+            s.insert_unchecked(s.len(), '!');
+        }
+        assert_eq!(s, "h\u{1F600}ll!");
+    }
+
+    #[test]
+    #[should_panic]
+    fn string_pop_failure() {
+        unsafe { String::new().pop_unchecked() };
+    }
+
+    #[test]
+    #[should_panic]
+    fn string_remove_failure() {
+        let mut s = String::from("h\u{e9}llo");
+        unsafe { s.remove_unchecked(2) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn string_insert_failure() {
+        let mut s = String::from("h\u{e9}llo");
+        unsafe { s.insert_unchecked(2, 'x') };
+    }
+
+    #[test]
+    fn string_push_success() {
+        let mut s = String::with_capacity(6);
+        unsafe {
+            s.push_str_unchecked("ab");
+            s.push_unchecked('\u{1F600}');
+        }
+        assert_eq!(s, "ab\u{1F600}");
+    }
+
+    #[test]
+    #[should_panic]
+    fn string_push_failure() {
+        let mut s = String::from("abc");
+        s.shrink_to_fit();
+        assert_eq!(s.capacity(), s.len());
+        unsafe { s.push_unchecked('\u{1F600}') };
+    }
+
+    #[test]
+    #[should_panic]
+    fn string_push_str_failure() {
+        unsafe { String::new().push_str_unchecked("a") };
+    }
+
+    #[test]
+    fn vec_into_array_success() {
+        let v: Vec<_> = ["a", "b"].iter().map(|&s| String::from(s)).collect();
+        let [a, b] = unsafe { vec_into_array_unchecked::<2, _>(v) };
+        assert_eq!((a.as_str(), b.as_str()), ("a", "b"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn vec_into_array_failure() {
+        unsafe { vec_into_array_unchecked::<2, _>(Vec::from([1])) };
+    }
+
+    #[test]
+    fn boxed_slice_into_array_success() {
+        let slice: Box<[u8]> = Box::new([1, 2, 3]);
+        let array = unsafe { boxed_slice_into_array_unchecked::<3, _>(slice) };
+        assert_eq!(*array, [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn boxed_slice_into_array_failure() {
+        let slice: Box<[u8]> = Box::new([1, 2, 3]);
+        unsafe { boxed_slice_into_array_unchecked::<2, _>(slice) };
+    }
+
+    #[test]
+    fn vec_deque_success() {
+        let mut deque: VecDeque<_> = (0..4).collect();
+        deque.pop_front();
+        unsafe {
+            deque.assume_contiguous_mut()[0] = 10;
+            assert_eq!(deque.assume_contiguous(), [10, 2, 3]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn vec_deque_failure() {
+        let mut deque = VecDeque::with_capacity(2);
+        deque.push_back(1);
+        deque.push_front(0);
+        unsafe { deque.assume_contiguous() };
+    }
+
+    #[test]
+    fn binary_heap_success() {
+        let mut heap: BinaryHeap<_> = [2, 7, 1].iter().cloned().collect();
+        unsafe {
+            assert_eq!(*heap.peek_unchecked(), 7);
+            assert_eq!(heap.pop_unchecked(), 7);
+            assert_eq!(heap.pop_unchecked(), 2);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn binary_heap_peek_failure() {
+        unsafe { BinaryHeap::<u8>::new().peek_unchecked() };
+    }
+
+    #[test]
+    #[should_panic]
+    fn binary_heap_pop_failure() {
+        unsafe { BinaryHeap::<u8>::new().pop_unchecked() };
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash_map_entry_success() {
+        let mut map = HashMap::new();
+        map.insert("a", 1);
+        unsafe {
+            *map.entry_existing("a") += 1;
+            assert_eq!(map.occupied_entry_unchecked("a").remove(), 2);
+        }
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "std")]
+    fn hash_map_entry_failure() {
+        let mut map = HashMap::<_, u8>::new();
+        unsafe { map.entry_existing("a") };
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash_map_get_success() {
+        let mut map = HashMap::new();
+        map.insert(String::from("a"), 1);
+        unsafe {
+            *map.get_existing_mut("a") += 1;
+            assert_eq!(*map.get_existing("a"), 2);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "std")]
+    fn hash_map_get_failure() {
+        let map = HashMap::<String, u8>::new();
+        unsafe { map.get_existing("a") };
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash_set_success() {
+        let mut set = HashSet::new();
+        set.insert(String::from("a"));
+        assert_eq!(unsafe { set.get_existing("a") }, "a");
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "std")]
+    fn hash_set_failure() {
+        unsafe { HashSet::<String>::new().get_existing("a") };
+    }
+
+    #[test]
+    fn btree_map_success() {
+        let mut map: BTreeMap<_, _> = (1..4).map(|k| (k, k * 10)).collect();
+        unsafe {
+            assert_eq!(map.first_key_value_unchecked(), (&1, &10));
+            assert_eq!(map.last_key_value_unchecked(), (&3, &30));
+            assert_eq!(map.pop_first_unchecked(), (1, 10));
+            assert_eq!(map.pop_last_unchecked(), (3, 30));
+        }
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn btree_map_first_failure() {
+        unsafe { BTreeMap::<u8, u8>::new().first_key_value_unchecked() };
+    }
+
+    #[test]
+    #[should_panic]
+    fn btree_map_pop_last_failure() {
+        unsafe { BTreeMap::<u8, u8>::new().pop_last_unchecked() };
+    }
+}
@@ -0,0 +1,67 @@
+//! Unchecked access to environment variables.
+//!
+//! These are intended for required variables that were validated at startup.
+//! This module requires the `std` feature.
+
+use std::env;
+use std::ffi::{OsStr, OsString};
+use std::string::String;
+
+use UnsafeUnwrap;
+
+/// Returns the value of the environment variable `key`, assuming that it is
+/// set and valid Unicode.
+///
+/// # Safety
+///
+/// `env::var(key)` must be `Ok`. This is checked in debug builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::env::env_var_unchecked;
+///
+/// std::env::set_var("WORKERS", "8");
+/// let workers = unsafe { env_var_unchecked("WORKERS") };
+/// assert_eq!(workers, "8");
+/// ```
+#[inline]
+pub unsafe fn env_var_unchecked<K: AsRef<OsStr>>(key: K) -> String {
+    env::var(key).unsafe_unwrap()
+}
+
+/// Returns the value of the environment variable `key`, assuming that it is
+/// set.
+///
+/// # Safety
+///
+/// `env::var_os(key)` must be `Some`. This is checked in debug builds.
+#[inline]
+pub unsafe fn env_var_os_unchecked<K: AsRef<OsStr>>(key: K) -> OsString {
+    env::var_os(key).unsafe_unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_var_success() {
+        unsafe {
+            assert_eq!(env_var_unchecked("CARGO_PKG_NAME"), "unsafe_unwrap");
+            assert_eq!(env_var_os_unchecked("CARGO_PKG_NAME"), "unsafe_unwrap");
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn env_var_failure() {
+        unsafe { env_var_unchecked("UNSAFE_UNWRAP_UNSET_VARIABLE") };
+    }
+
+    #[test]
+    #[should_panic]
+    fn env_var_os_failure() {
+        unsafe { env_var_os_unchecked("UNSAFE_UNWRAP_UNSET_VARIABLE") };
+    }
+}
@@ -0,0 +1,376 @@
+//! Optimizer hints for invariants that are known to hold.
+//!
+//! Each hint is checked in debug builds and turned into an assumption in
+//! optimized builds, in the same way as `unsafe_unwrap()`.
+//!
+//! The safe [`ColdOption`] and [`ColdResult`] traits keep their checks, but
+//! move the fallback path out of line.
+//!
+//! [`ColdOption`]: trait.ColdOption.html
+//! [`ColdResult`]: trait.ColdResult.html
+
+use core::slice;
+
+use assume;
+
+/// Tells the optimizer that `a` and `b` have the same length.
+///
+/// This allows bounds checks to be removed from loops that index into both
+/// slices, which often lets element-wise kernels be vectorized.
+///
+/// # Safety
+///
+/// `a.len()` must equal `b.len()`. This is checked in debug builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::hints::assume_len_eq;
+///
+/// fn add(dst: &mut [f32], src: &[f32]) {
+///     assert_eq!(dst.len(), src.len());
+///     unsafe { assume_len_eq(dst, src) };
+///     for i in 0..dst.len() {
+///         dst[i] += src[i];
+///     }
+/// }
+/// # add(&mut [1.0], &[2.0]);
+/// ```
+#[inline(always)]
+pub unsafe fn assume_len_eq<T, U>(a: &[T], b: &[U]) {
+    assume(a.len() == b.len());
+}
+
+/// Tells the optimizer that `a`, `b`, and `c` have the same length.
+///
+/// # Safety
+///
+/// All three slices must have the same length. This is checked in debug
+/// builds.
+#[inline(always)]
+pub unsafe fn assume_len_eq3<T, U, V>(a: &[T], b: &[U], c: &[V]) {
+    assume(a.len() == b.len() && b.len() == c.len());
+}
+
+/// Tells the optimizer that the length of `slice` is a multiple of `n`, which
+/// must be non-zero.
+///
+/// This removes remainder handling from loops that step through `slice` by
+/// `n` elements, such as over `chunks_exact(n)`.
+///
+/// # Safety
+///
+/// `n` must be non-zero and `slice.len()` must be a multiple of `n`. This is
+/// checked in debug builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::hints::assume_divisible_by;
+///
+/// fn luma(rgb: &[u8]) -> u32 {
+///     unsafe { assume_divisible_by(rgb, 3) };
+///     rgb.chunks_exact(3).map(|p| p[1] as u32).sum()
+/// }
+/// # assert_eq!(luma(&[0, 1, 0, 0, 2, 0]), 3);
+/// ```
+#[inline(always)]
+pub unsafe fn assume_divisible_by<T>(slice: &[T], n: usize) {
+    assume(n != 0 && slice.len().is_multiple_of(n));
+}
+
+/// Tells the optimizer that `ptr` is aligned to `N` bytes, returning it.
+///
+/// The returned pointer should be used for subsequent accesses, which allows
+/// SIMD loops to skip their scalar alignment prologues.
+///
+/// `N` must be a power of two, which is checked at compile time.
+///
+/// # Safety
+///
+/// `ptr` must be a multiple of `N`. This is checked in debug builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::hints::assume_aligned;
+///
+/// #[repr(align(32))]
+/// struct Block([f32; 8]);
+///
+/// let block = Block([0.0; 8]);
+/// let ptr = unsafe { assume_aligned::<32, f32>(block.0.as_ptr()) };
+/// ```
+#[inline(always)]
+pub unsafe fn assume_aligned<const N: usize, T>(ptr: *const T) -> *const T {
+    const { assert!(N.is_power_of_two(), "alignment must be a power of two") };
+    assume(ptr as usize & (N - 1) == 0);
+    ptr
+}
+
+/// Tells the optimizer that `ptr` is aligned to `N` bytes, returning it.
+///
+/// This is the mutable equivalent of [`assume_aligned`].
+///
+/// # Safety
+///
+/// `ptr` must be a multiple of `N`. This is checked in debug builds.
+///
+/// [`assume_aligned`]: fn.assume_aligned.html
+#[inline(always)]
+pub unsafe fn assume_aligned_mut<const N: usize, T>(ptr: *mut T) -> *mut T {
+    assume_aligned::<N, T>(ptr) as *mut T
+}
+
+/// Tells the optimizer that the start of `slice` is aligned to `N` bytes,
+/// returning it.
+///
+/// # Safety
+///
+/// `slice.as_ptr()` must be a multiple of `N`. This is checked in debug
+/// builds.
+#[inline(always)]
+pub unsafe fn assume_slice_aligned<const N: usize, T>(slice: &[T]) -> &[T] {
+    let ptr = assume_aligned::<N, T>(slice.as_ptr());
+    slice::from_raw_parts(ptr, slice.len())
+}
+
+/// Tells the optimizer that the start of `slice` is aligned to `N` bytes,
+/// returning it.
+///
+/// # Safety
+///
+/// `slice.as_ptr()` must be a multiple of `N`. This is checked in debug
+/// builds.
+#[inline(always)]
+pub unsafe fn assume_slice_aligned_mut<const N: usize, T>(
+    slice: &mut [T],
+) -> &mut [T] {
+    let ptr = assume_aligned_mut::<N, T>(slice.as_mut_ptr());
+    slice::from_raw_parts_mut(ptr, slice.len())
+}
+
+/// Tells the optimizer that `ptr` is not null.
+///
+/// # Safety
+///
+/// `ptr` must not be null. This is checked in debug builds.
+#[inline(always)]
+pub unsafe fn assume_non_null<T: ?Sized>(ptr: *const T) {
+    assume(!ptr.is_null());
+}
+
+/// Converts `ptr` into a shared reference, assuming it is not null.
+///
+/// This is shorthand for `ptr.as_ref().unsafe_unwrap()`.
+///
+/// # Safety
+///
+/// `ptr` must not be null, which is checked in debug builds, and must
+/// otherwise satisfy the requirements of [`pointer::as_ref`].
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::hints::non_null_ref;
+///
+/// extern "C" fn callback(len: *const usize) -> usize {
+///     // The caller guarantees that `len` is valid.
+///     unsafe { *non_null_ref(len) }
+/// }
+/// # assert_eq!(callback(&4), 4);
+/// ```
+///
+/// [`pointer::as_ref`]: https://doc.rust-lang.org/std/primitive.pointer.html#method.as_ref
+#[inline(always)]
+pub unsafe fn non_null_ref<'a, T: ?Sized>(ptr: *const T) -> &'a T {
+    assume_non_null(ptr);
+    &*ptr
+}
+
+/// Converts `ptr` into a mutable reference, assuming it is not null.
+///
+/// This is shorthand for `ptr.as_mut().unsafe_unwrap()`.
+///
+/// # Safety
+///
+/// `ptr` must not be null, which is checked in debug builds, and must
+/// otherwise satisfy the requirements of [`pointer::as_mut`].
+///
+/// [`pointer::as_mut`]: https://doc.rust-lang.org/std/primitive.pointer.html#method.as_mut
+#[inline(always)]
+pub unsafe fn non_null_mut<'a, T: ?Sized>(ptr: *mut T) -> &'a mut T {
+    assume_non_null(ptr);
+    &mut *ptr
+}
+
+/// Calls `f` from an outlined function that is marked as cold.
+#[cold]
+#[inline(never)]
+fn cold<R, F: FnOnce() -> R>(f: F) -> R {
+    f()
+}
+
+/// Fallbacks for `Option` that are kept out of the hot path.
+pub trait ColdOption<T> {
+    /// Returns the contained value or computes it from `f`, which is called
+    /// from a cold, outlined function.
+    ///
+    /// Unlike `unsafe_unwrap()`, the check is kept, so this is safe when
+    /// `None` is rare but possible.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::hints::ColdOption;
+    ///
+    /// fn lookup(cache: &[Option<u32>], i: usize) -> u32 {
+    ///     cache[i].unwrap_or_else_cold(|| (i as u32).pow(2))
+    /// }
+    /// assert_eq!(lookup(&[Some(7), None], 0), 7);
+    /// assert_eq!(lookup(&[Some(7), None], 1), 1);
+    /// ```
+    fn unwrap_or_else_cold<F: FnOnce() -> T>(self, f: F) -> T;
+
+    /// Converts `self` into a `Result`, mapping `None` to the error computed
+    /// by `f`, which is called from a cold, outlined function.
+    fn ok_or_else_cold<E, F: FnOnce() -> E>(self, f: F) -> Result<T, E>;
+}
+
+impl<T> ColdOption<T> for Option<T> {
+    #[inline]
+    fn unwrap_or_else_cold<F: FnOnce() -> T>(self, f: F) -> T {
+        match self {
+            Some(x) => x,
+            None => cold(f),
+        }
+    }
+
+    #[inline]
+    fn ok_or_else_cold<E, F: FnOnce() -> E>(self, f: F) -> Result<T, E> {
+        match self {
+            Some(x) => Ok(x),
+            None => Err(cold(f)),
+        }
+    }
+}
+
+/// Fallbacks for `Result` that are kept out of the hot path.
+pub trait ColdResult<T, E> {
+    /// Returns the contained `Ok` value or computes it from the error with
+    /// `f`, which is called from a cold, outlined function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::hints::ColdResult;
+    ///
+    /// let port = "80".parse::<u16>().unwrap_or_else_cold(|_| 8080);
+    /// assert_eq!(port, 80);
+    /// ```
+    fn unwrap_or_else_cold<F: FnOnce(E) -> T>(self, f: F) -> T;
+}
+
+impl<T, E> ColdResult<T, E> for Result<T, E> {
+    #[inline]
+    fn unwrap_or_else_cold<F: FnOnce(E) -> T>(self, f: F) -> T {
+        match self {
+            Ok(x) => x,
+            Err(e) => cold(move || f(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn len_eq_success() {
+        unsafe {
+            assume_len_eq(&[0u8; 4], &[0u32; 4]);
+            assume_len_eq3(&[0u8; 4], &[0u32; 4], &[(); 4]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn len_eq_failure() {
+        unsafe { assume_len_eq(&[0u8; 4], &[0u8; 3]) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn len_eq3_failure() {
+        unsafe { assume_len_eq3(&[0u8; 4], &[0u8; 4], &[0u8; 3]) };
+    }
+
+    #[test]
+    fn divisible_by_success() {
+        unsafe {
+            assume_divisible_by(&[0u8; 6], 3);
+            assume_divisible_by(&[0u8; 0], 3);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn divisible_by_failure() {
+        unsafe { assume_divisible_by(&[0u8; 6], 4) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn divisible_by_zero_failure() {
+        unsafe { assume_divisible_by(&[0u8; 0], 0) };
+    }
+
+    #[repr(align(16))]
+    struct Aligned([u8; 32]);
+
+    #[test]
+    fn aligned_success() {
+        let mut buf = Aligned([0; 32]);
+        unsafe {
+            assume_aligned::<16, u8>(buf.0.as_ptr());
+            assume_slice_aligned_mut::<8, u8>(&mut buf.0[8..])[0] = 1;
+        }
+        assert_eq!(buf.0[8], 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn aligned_failure() {
+        let buf = Aligned([0; 32]);
+        unsafe { assume_slice_aligned::<16, u8>(&buf.0[1..]) };
+    }
+
+    #[test]
+    fn non_null_success() {
+        let mut x = 1;
+        unsafe {
+            *non_null_mut(&mut x as *mut i32) += 1;
+            assert_eq!(*non_null_ref(&x as *const i32), 2);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn non_null_failure() {
+        unsafe { non_null_ref(core::ptr::null::<u8>()) };
+    }
+
+    #[test]
+    fn cold_option() {
+        assert_eq!(Some(1).unwrap_or_else_cold(|| 2), 1);
+        assert_eq!(None.unwrap_or_else_cold(|| 2), 2);
+        assert_eq!(Some(1).ok_or_else_cold(|| "none"), Ok(1));
+        assert_eq!(None::<u8>.ok_or_else_cold(|| "none"), Err("none"));
+    }
+
+    #[test]
+    fn cold_result() {
+        assert_eq!(Ok::<u8, u8>(1).unwrap_or_else_cold(|e| e + 1), 1);
+        assert_eq!(Err::<u8, u8>(1).unwrap_or_else_cold(|e| e + 1), 2);
+    }
+}
@@ -0,0 +1,123 @@
+//! Unchecked indexing.
+//!
+//! Unlike `get_unchecked` and similar inherent methods, these check bounds in
+//! debug builds, following the same failure policy as the rest of the crate.
+//! With the `ndarray` feature, they are also implemented for `ndarray`
+//! arrays.
+
+use core::slice::SliceIndex;
+
+use UnsafeUnwrap;
+
+/// Indexing that assumes `index` is in bounds.
+pub trait UnsafeIndex<I> {
+    /// The type of the indexed value.
+    type Output: ?Sized;
+
+    /// Returns a reference to the value at `index`, assuming that it is in
+    /// bounds.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be in bounds. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::index::UnsafeIndex;
+    ///
+    /// let row = [1, 2, 3, 4];
+    /// unsafe {
+    ///     assert_eq!(*row.index_unchecked(2), 3);
+    ///     assert_eq!(row.index_unchecked(1..3), [2, 3]);
+    /// }
+    /// ```
+    unsafe fn index_unchecked(&self, index: I) -> &Self::Output;
+
+    /// Returns references to the values at each of `indices`, assuming that
+    /// they are all in bounds.
+    ///
+    /// This suits stencil kernels, which read a fixed set of neighbors.
+    ///
+    /// # Safety
+    ///
+    /// Every index must be in bounds. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::index::UnsafeIndex;
+    ///
+    /// let signal = [1.0, 4.0, 2.0, 8.0];
+    /// let i = 2;
+    /// let [l, c, r] = unsafe { signal.index_many_unchecked([i - 1, i, i + 1]) };
+    /// assert_eq!(l + c + r, 14.0);
+    /// ```
+    #[inline]
+    unsafe fn index_many_unchecked<const N: usize>(
+        &self,
+        indices: [I; N],
+    ) -> [&Self::Output; N] {
+        indices.map(|index| self.index_unchecked(index))
+    }
+}
+
+/// Mutable indexing that assumes `index` is in bounds.
+pub trait UnsafeIndexMut<I>: UnsafeIndex<I> {
+    /// Returns a mutable reference to the value at `index`, assuming that it
+    /// is in bounds.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be in bounds. This is checked in debug builds.
+    unsafe fn index_unchecked_mut(&mut self, index: I) -> &mut Self::Output;
+}
+
+impl<T, I: SliceIndex<[T]>> UnsafeIndex<I> for [T] {
+    type Output = I::Output;
+
+    #[inline]
+    unsafe fn index_unchecked(&self, index: I) -> &I::Output {
+        self.get(index).unsafe_unwrap()
+    }
+}
+
+impl<T, I: SliceIndex<[T]>> UnsafeIndexMut<I> for [T] {
+    #[inline]
+    unsafe fn index_unchecked_mut(&mut self, index: I) -> &mut I::Output {
+        self.get_mut(index).unsafe_unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slice_success() {
+        let mut xs = [1, 2, 3];
+        unsafe {
+            *xs.index_unchecked_mut(0) = 4;
+            assert_eq!(xs.index_unchecked(..2), [4, 2]);
+            assert_eq!(xs.index_many_unchecked([2, 0]), [&3, &4]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_failure() {
+        unsafe { [1, 2, 3].index_unchecked(3) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_many_failure() {
+        unsafe { [1, 2, 3].index_many_unchecked([0, 3]) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_mut_failure() {
+        unsafe { [1, 2, 3].index_unchecked_mut(2..4) };
+    }
+}
@@ -0,0 +1,92 @@
+//! Assumed-type downcasting of [`anyhow`](https://docs.rs/anyhow) errors.
+//!
+//! These are intended for error-classification layers that have already
+//! matched on the error chain and only need to extract the payload.
+
+use core::fmt::{Debug, Display};
+
+use anyhow::Error;
+
+use UnsafeUnwrap;
+
+/// Downcasting of an `anyhow::Error` whose concrete type is known.
+pub trait UnsafeError {
+    /// Extracts the error of type `E`, assuming that it is the concrete type
+    /// of `self`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must hold an error of type `E`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::num::ParseIntError;
+    /// use unsafe_unwrap::interop::anyhow::UnsafeError;
+    ///
+    /// let err = anyhow::Error::new("x".parse::<u8>().unwrap_err());
+    /// if err.is::<ParseIntError>() {
+    ///     let err = unsafe { err.downcast_unchecked::<ParseIntError>() };
+    ///     assert_eq!(err.to_string(), "invalid digit found in string");
+    /// }
+    /// ```
+    unsafe fn downcast_unchecked<E>(self) -> E
+        where E: Display + Debug + Send + Sync + 'static;
+
+    /// Returns a reference to the error of type `E`, assuming that it is in
+    /// the chain of `self`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must hold or wrap an error of type `E`. This is checked in debug
+    /// builds.
+    unsafe fn downcast_ref_unchecked<E>(&self) -> &E
+        where E: Display + Debug + Send + Sync + 'static;
+}
+
+impl UnsafeError for Error {
+    #[inline]
+    unsafe fn downcast_unchecked<E>(self) -> E
+        where E: Display + Debug + Send + Sync + 'static
+    {
+        self.downcast().unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn downcast_ref_unchecked<E>(&self) -> &E
+        where E: Display + Debug + Send + Sync + 'static
+    {
+        self.downcast_ref().unsafe_unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error() -> Error {
+        Error::new(core::fmt::Error).context("outer")
+    }
+
+    #[test]
+    fn downcast_success() {
+        let err = error();
+        unsafe {
+            assert_eq!(*err.downcast_ref_unchecked::<&str>(), "outer");
+            err.downcast_ref_unchecked::<core::fmt::Error>();
+            assert_eq!(Error::msg(1u8).downcast_unchecked::<u8>(), 1);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn downcast_failure() {
+        unsafe { error().downcast_unchecked::<u8>() };
+    }
+
+    #[test]
+    #[should_panic]
+    fn downcast_ref_failure() {
+        unsafe { error().downcast_ref_unchecked::<u8>() };
+    }
+}
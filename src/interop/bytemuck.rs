@@ -0,0 +1,123 @@
+//! Unchecked byte reinterpretation with [`bytemuck`](https://docs.rs/bytemuck).
+//!
+//! Each function runs the corresponding `try_*` validation in debug builds and
+//! assumes that it succeeds in optimized builds, so size and alignment checks
+//! are paid once, such as when validating a frame header, rather than for
+//! every field view.
+
+use bytemuck::{self, AnyBitPattern, NoUninit};
+
+use UnsafeUnwrap;
+
+/// Reinterprets `bytes` as a `&T` without checking its size or alignment.
+///
+/// # Safety
+///
+/// `bytemuck::try_from_bytes::<T>(bytes)` must be `Ok`: `bytes` must be
+/// exactly `size_of::<T>()` long and aligned for `T`. This is checked in debug
+/// builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::interop::bytemuck::from_bytes_unchecked;
+///
+/// let words = [0x0102_0304u32];
+/// let bytes: &[u8] = bytemuck::cast_slice(&words);
+/// let word: &u32 = unsafe { from_bytes_unchecked(bytes) };
+/// assert_eq!(*word, 0x0102_0304);
+/// ```
+#[inline]
+pub unsafe fn from_bytes_unchecked<T: AnyBitPattern>(bytes: &[u8]) -> &T {
+    bytemuck::try_from_bytes(bytes).unsafe_unwrap()
+}
+
+/// Reinterprets `bytes` as a `&mut T` without checking its size or alignment.
+///
+/// # Safety
+///
+/// `bytemuck::try_from_bytes_mut::<T>(bytes)` must be `Ok`. This is checked in
+/// debug builds.
+#[inline]
+pub unsafe fn from_bytes_unchecked_mut<T>(bytes: &mut [u8]) -> &mut T
+    where T: NoUninit + AnyBitPattern
+{
+    bytemuck::try_from_bytes_mut(bytes).unsafe_unwrap()
+}
+
+/// Reads a `T` from possibly unaligned `bytes` without checking its size.
+///
+/// # Safety
+///
+/// `bytes` must be exactly `size_of::<T>()` long. This is checked in debug
+/// builds.
+#[inline]
+pub unsafe fn pod_read_unaligned_unchecked<T: AnyBitPattern>(bytes: &[u8]) -> T {
+    bytemuck::try_pod_read_unaligned(bytes).unsafe_unwrap()
+}
+
+/// Casts a slice of `A` to a slice of `B` without checking size or alignment.
+///
+/// # Safety
+///
+/// `bytemuck::try_cast_slice::<A, B>(slice)` must be `Ok`: `slice` must be
+/// aligned for `B` and its length in bytes must be a multiple of
+/// `size_of::<B>()`. This is checked in debug builds.
+#[inline]
+pub unsafe fn cast_slice_unchecked<A, B>(slice: &[A]) -> &[B]
+    where A: NoUninit, B: AnyBitPattern
+{
+    bytemuck::try_cast_slice(slice).unsafe_unwrap()
+}
+
+/// Casts a mutable slice of `A` to a slice of `B` without checking size or
+/// alignment.
+///
+/// # Safety
+///
+/// `bytemuck::try_cast_slice_mut::<A, B>(slice)` must be `Ok`. This is checked
+/// in debug builds.
+#[inline]
+pub unsafe fn cast_slice_unchecked_mut<A, B>(slice: &mut [A]) -> &mut [B]
+    where A: NoUninit + AnyBitPattern, B: NoUninit + AnyBitPattern
+{
+    bytemuck::try_cast_slice_mut(slice).unsafe_unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_bytes_success() {
+        let mut words = [1u32, 2];
+        unsafe {
+            let bytes: &mut [u8] = cast_slice_unchecked_mut(&mut words);
+            *from_bytes_unchecked_mut::<u32>(&mut bytes[4..]) = 3;
+            assert_eq!(*from_bytes_unchecked::<u32>(&bytes[..4]), 1);
+            let unaligned = &bytes[1..5];
+            let expected = u32::from_ne_bytes([
+                unaligned[0], unaligned[1], unaligned[2], unaligned[3],
+            ]);
+            assert_eq!(pod_read_unaligned_unchecked::<u32>(unaligned), expected);
+        }
+        assert_eq!(words, [1, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_bytes_failure() {
+        let words = [1u32, 2];
+        unsafe {
+            let bytes: &[u8] = cast_slice_unchecked(&words);
+            from_bytes_unchecked::<u32>(&bytes[..3]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn cast_slice_failure() {
+        let bytes = [0u8; 3];
+        unsafe { cast_slice_unchecked::<u8, [u8; 2]>(&bytes) };
+    }
+}
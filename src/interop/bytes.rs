@@ -0,0 +1,133 @@
+//! Unchecked buffer reads with [`bytes`](https://docs.rs/bytes).
+//!
+//! These are intended for frame decoders that validate the total length of a
+//! frame once and then read its fields without a remaining-length check each.
+
+use bytes::Buf;
+
+use {UnsafeUnwrap, assume};
+
+macro_rules! get_unchecked {
+    ($($get:ident => $try_get:ident($ty:ty), $desc:literal;)+) => {
+        $(
+            #[doc = concat!(
+                "Reads ", $desc, ", assuming that enough bytes are remaining.",
+            )]
+            ///
+            /// # Safety
+            ///
+            /// There must be enough bytes remaining. This is checked in debug
+            /// builds.
+            #[inline]
+            unsafe fn $get(&mut self) -> $ty {
+                self.$try_get().unsafe_unwrap()
+            }
+        )+
+    };
+}
+
+/// Reads from a `Buf` that assume enough bytes are remaining.
+///
+/// This is implemented for every `Buf`.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::interop::bytes::UnsafeBuf;
+///
+/// // A 2-byte tag and a 4-byte length, validated as a whole.
+/// let mut frame: &[u8] = &[0x00, 0x07, 0x00, 0x00, 0x01, 0x00, 0xff];
+/// if bytes::Buf::remaining(&frame) >= 6 {
+///     let tag = unsafe { frame.get_u16_unchecked() };
+///     let len = unsafe { frame.get_u32_unchecked() };
+///     assert_eq!((tag, len), (7, 256));
+/// }
+/// ```
+pub trait UnsafeBuf: Buf {
+    /// Advances the read position by `n`, assuming that `n` bytes are
+    /// remaining.
+    ///
+    /// # Safety
+    ///
+    /// `n` must be at most `remaining()`. This is checked in debug builds.
+    #[inline]
+    unsafe fn advance_unchecked(&mut self, n: usize) {
+        assume(n <= self.remaining());
+        self.advance(n)
+    }
+
+    /// Copies bytes into `dst`, assuming that `dst.len()` bytes are
+    /// remaining.
+    ///
+    /// # Safety
+    ///
+    /// `dst.len()` must be at most `remaining()`. This is checked in debug
+    /// builds.
+    #[inline]
+    unsafe fn copy_to_slice_unchecked(&mut self, dst: &mut [u8]) {
+        self.try_copy_to_slice(dst).unsafe_unwrap()
+    }
+
+    get_unchecked! {
+        get_u8_unchecked => try_get_u8(u8), "a `u8`";
+        get_i8_unchecked => try_get_i8(i8), "an `i8`";
+        get_u16_unchecked => try_get_u16(u16), "a big-endian `u16`";
+        get_u16_le_unchecked => try_get_u16_le(u16), "a little-endian `u16`";
+        get_i16_unchecked => try_get_i16(i16), "a big-endian `i16`";
+        get_i16_le_unchecked => try_get_i16_le(i16), "a little-endian `i16`";
+        get_u32_unchecked => try_get_u32(u32), "a big-endian `u32`";
+        get_u32_le_unchecked => try_get_u32_le(u32), "a little-endian `u32`";
+        get_i32_unchecked => try_get_i32(i32), "a big-endian `i32`";
+        get_i32_le_unchecked => try_get_i32_le(i32), "a little-endian `i32`";
+        get_u64_unchecked => try_get_u64(u64), "a big-endian `u64`";
+        get_u64_le_unchecked => try_get_u64_le(u64), "a little-endian `u64`";
+        get_i64_unchecked => try_get_i64(i64), "a big-endian `i64`";
+        get_i64_le_unchecked => try_get_i64_le(i64), "a little-endian `i64`";
+        get_f32_unchecked => try_get_f32(f32), "a big-endian `f32`";
+        get_f32_le_unchecked => try_get_f32_le(f32), "a little-endian `f32`";
+        get_f64_unchecked => try_get_f64(f64), "a big-endian `f64`";
+        get_f64_le_unchecked => try_get_f64_le(f64), "a little-endian `f64`";
+    }
+}
+
+impl<B: Buf + ?Sized> UnsafeBuf for B {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_success() {
+        let mut buf: &[u8] = &[1, 0, 2, 3, 0, 0, 0, 0xff, 0xff];
+        unsafe {
+            assert_eq!(buf.get_u8_unchecked(), 1);
+            assert_eq!(buf.get_u16_unchecked(), 2);
+            assert_eq!(buf.get_u32_le_unchecked(), 3);
+            buf.advance_unchecked(1);
+            assert_eq!(buf.get_i8_unchecked(), -1);
+        }
+        assert!(!buf.has_remaining());
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_failure() {
+        let mut buf: &[u8] = &[0; 3];
+        unsafe { buf.get_u32_unchecked() };
+    }
+
+    #[test]
+    fn copy_to_slice_success() {
+        let mut buf: &[u8] = b"abc";
+        let mut dst = [0; 2];
+        unsafe { buf.copy_to_slice_unchecked(&mut dst) };
+        assert_eq!((&dst, buf), (b"ab", &b"c"[..]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn advance_failure() {
+        let mut buf: &[u8] = &[0; 3];
+        unsafe { buf.advance_unchecked(4) };
+    }
+}
@@ -0,0 +1,129 @@
+//! Unchecked date and time construction with [`chrono`](https://docs.rs/chrono).
+//!
+//! These are intended for reconstructing timestamps from fields that were
+//! validated when they were first recorded.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+use UnsafeUnwrap;
+
+/// Creates a date from the year, month, and day without checking them.
+///
+/// # Safety
+///
+/// `NaiveDate::from_ymd_opt(year, month, day)` must be `Some`. This is checked
+/// in debug builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::interop::chrono::date_from_ymd_unchecked;
+///
+/// let date = unsafe { date_from_ymd_unchecked(2024, 2, 29) };
+/// assert_eq!(date.to_string(), "2024-02-29");
+/// ```
+#[inline]
+pub unsafe fn date_from_ymd_unchecked(
+    year: i32,
+    month: u32,
+    day: u32,
+) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unsafe_unwrap()
+}
+
+/// Creates a time from the hour, minute, and second without checking them.
+///
+/// # Safety
+///
+/// `NaiveTime::from_hms_opt(hour, min, sec)` must be `Some`. This is checked
+/// in debug builds.
+#[inline]
+pub unsafe fn time_from_hms_unchecked(
+    hour: u32,
+    min: u32,
+    sec: u32,
+) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, min, sec).unsafe_unwrap()
+}
+
+/// Creates a time from the hour, minute, second, and nanosecond without
+/// checking them.
+///
+/// # Safety
+///
+/// `NaiveTime::from_hms_nano_opt(hour, min, sec, nano)` must be `Some`. This
+/// is checked in debug builds.
+#[inline]
+pub unsafe fn time_from_hms_nano_unchecked(
+    hour: u32,
+    min: u32,
+    sec: u32,
+    nano: u32,
+) -> NaiveTime {
+    NaiveTime::from_hms_nano_opt(hour, min, sec, nano).unsafe_unwrap()
+}
+
+/// Creates a UTC date and time from a Unix timestamp without checking its
+/// range.
+///
+/// # Safety
+///
+/// `DateTime::from_timestamp(secs, nsecs)` must be `Some`. This is checked in
+/// debug builds.
+#[inline]
+pub unsafe fn datetime_from_timestamp_unchecked(
+    secs: i64,
+    nsecs: u32,
+) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, nsecs).unsafe_unwrap()
+}
+
+/// Parses a date and time from `s` in the format `fmt`, assuming that it
+/// matches.
+///
+/// # Safety
+///
+/// `NaiveDateTime::parse_from_str(s, fmt)` must be `Ok`. This is checked in
+/// debug builds.
+#[inline]
+pub unsafe fn parse_from_str_unchecked(s: &str, fmt: &str) -> NaiveDateTime {
+    NaiveDateTime::parse_from_str(s, fmt).unsafe_unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn datetime_success() {
+        unsafe {
+            let time = time_from_hms_unchecked(1, 46, 40);
+            let datetime = date_from_ymd_unchecked(2001, 9, 9).and_time(time);
+            assert_eq!(
+                parse_from_str_unchecked("2001-09-09 01:46:40", "%F %T"),
+                datetime,
+            );
+            let utc = datetime_from_timestamp_unchecked(1_000_000_000, 5);
+            assert_eq!(utc.time(), time_from_hms_nano_unchecked(1, 46, 40, 5));
+            assert_eq!(utc.date_naive(), datetime.date());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn date_failure() {
+        unsafe { date_from_ymd_unchecked(2023, 2, 29) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn time_failure() {
+        unsafe { time_from_hms_unchecked(24, 0, 0) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn parse_failure() {
+        unsafe { parse_from_str_unchecked("2001-09-09", "%F %T") };
+    }
+}
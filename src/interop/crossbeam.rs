@@ -0,0 +1,111 @@
+//! Unchecked queue operations with
+//! [`crossbeam-queue`](https://docs.rs/crossbeam-queue).
+//!
+//! These are intended for pipelines whose stage protocol already enforces
+//! capacity and non-emptiness, such as by counting messages in flight.
+
+use crossbeam_queue::{ArrayQueue, SegQueue};
+
+use UnsafeUnwrap;
+
+/// Unchecked operations on `ArrayQueue`.
+pub trait UnsafeArrayQueue<T> {
+    /// Pushes `value` into the queue, assuming that it is not full.
+    ///
+    /// # Safety
+    ///
+    /// The queue must not be full. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::interop::crossbeam::UnsafeArrayQueue;
+    ///
+    /// let queue = crossbeam_queue::ArrayQueue::new(2);
+    /// unsafe {
+    ///     queue.push_unchecked(1);
+    ///     queue.push_unchecked(2);
+    ///     assert_eq!(queue.pop_unchecked(), 1);
+    /// }
+    /// ```
+    unsafe fn push_unchecked(&self, value: T);
+
+    /// Pops a value from the queue, assuming that it is not empty.
+    ///
+    /// # Safety
+    ///
+    /// The queue must not be empty. This is checked in debug builds.
+    unsafe fn pop_unchecked(&self) -> T;
+}
+
+impl<T> UnsafeArrayQueue<T> for ArrayQueue<T> {
+    #[inline]
+    unsafe fn push_unchecked(&self, value: T) {
+        self.push(value).unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn pop_unchecked(&self) -> T {
+        self.pop().unsafe_unwrap()
+    }
+}
+
+/// Unchecked operations on `SegQueue`.
+pub trait UnsafeSegQueue<T> {
+    /// Pops a value from the queue, assuming that it is not empty.
+    ///
+    /// # Safety
+    ///
+    /// The queue must not be empty. This is checked in debug builds.
+    unsafe fn pop_unchecked(&self) -> T;
+}
+
+impl<T> UnsafeSegQueue<T> for SegQueue<T> {
+    #[inline]
+    unsafe fn pop_unchecked(&self) -> T {
+        self.pop().unsafe_unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn array_queue_success() {
+        let queue = ArrayQueue::new(1);
+        unsafe {
+            queue.push_unchecked(1);
+            assert_eq!(queue.pop_unchecked(), 1);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn array_queue_push_failure() {
+        let queue = ArrayQueue::new(1);
+        unsafe {
+            queue.push_unchecked(1);
+            queue.push_unchecked(2);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn array_queue_pop_failure() {
+        unsafe { ArrayQueue::<u8>::new(1).pop_unchecked() };
+    }
+
+    #[test]
+    fn seg_queue_success() {
+        let queue = SegQueue::new();
+        queue.push(1);
+        assert_eq!(unsafe { queue.pop_unchecked() }, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn seg_queue_failure() {
+        unsafe { SegQueue::<u8>::new().pop_unchecked() };
+    }
+}
@@ -0,0 +1,92 @@
+//! Unchecked concurrent map access with [`dashmap`](https://docs.rs/dashmap).
+//!
+//! These are intended for sharded caches whose keys are all inserted before
+//! any reader runs and are never removed.
+
+use core::borrow::Borrow;
+use core::hash::{BuildHasher, Hash};
+
+use dashmap::DashMap;
+use dashmap::mapref::one::{Ref, RefMut};
+
+use UnsafeUnwrap;
+
+/// Unchecked access to a `DashMap` whose keys are known to be present.
+pub trait UnsafeDashMap<K, V> {
+    /// Returns a guard for the value of `key`, assuming that it is present.
+    ///
+    /// # Safety
+    ///
+    /// `self` must contain `key`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::interop::dashmap::UnsafeDashMap;
+    ///
+    /// let limits = dashmap::DashMap::new();
+    /// limits.insert("uploads", 10u32);
+    /// // Every route is registered at startup.
+    /// unsafe { *limits.get_existing_mut("uploads") -= 1 };
+    /// assert_eq!(*unsafe { limits.get_existing("uploads") }, 9);
+    /// ```
+    unsafe fn get_existing<Q>(&self, key: &Q) -> Ref<'_, K, V>
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized;
+
+    /// Returns a mutable guard for the value of `key`, assuming that it is
+    /// present.
+    ///
+    /// # Safety
+    ///
+    /// `self` must contain `key`. This is checked in debug builds.
+    unsafe fn get_existing_mut<Q>(&self, key: &Q) -> RefMut<'_, K, V>
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized;
+}
+
+impl<K, V, S> UnsafeDashMap<K, V> for DashMap<K, V, S>
+    where K: Eq + Hash, S: BuildHasher + Clone
+{
+    #[inline]
+    unsafe fn get_existing<Q>(&self, key: &Q) -> Ref<'_, K, V>
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        self.get(key).unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn get_existing_mut<Q>(&self, key: &Q) -> RefMut<'_, K, V>
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        self.get_mut(key).unsafe_unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    #[test]
+    fn get_existing_success() {
+        let map = DashMap::new();
+        map.insert(String::from("a"), 1);
+        unsafe {
+            *map.get_existing_mut("a") += 1;
+            assert_eq!(*map.get_existing("a"), 2);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_existing_failure() {
+        let map = DashMap::<String, u8>::new();
+        unsafe { map.get_existing("a") };
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_existing_mut_failure() {
+        let map = DashMap::<String, u8>::new();
+        unsafe { map.get_existing_mut("a") };
+    }
+}
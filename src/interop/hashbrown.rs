@@ -0,0 +1,223 @@
+//! Unchecked operations on [`hashbrown`](https://docs.rs/hashbrown) maps and
+//! sets.
+//!
+//! This implements the hash map and set traits of
+//! [`collections`](../../collections/index.html) without requiring `std`, and
+//! adds raw-entry lookups for callers that have already computed a key's hash.
+
+use core::borrow::Borrow;
+use core::hash::{BuildHasher, Hash};
+
+use hashbrown::hash_map::{Entry, OccupiedEntry, RawEntryMut};
+use hashbrown::{Equivalent, HashMap, HashSet};
+
+use collections::{UnsafeHashMap, UnsafeHashSet};
+use {UnsafeUnwrap, unreachable};
+
+impl<K, V, S> UnsafeHashMap<K, V> for HashMap<K, V, S>
+    where K: Eq + Hash, S: BuildHasher
+{
+    type OccupiedEntry<'a> = OccupiedEntry<'a, K, V, S> where Self: 'a;
+
+    #[inline]
+    unsafe fn get_existing<Q>(&self, key: &Q) -> &V
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        self.get(key).unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn get_existing_mut<Q>(&mut self, key: &Q) -> &mut V
+        where K: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        self.get_mut(key).unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn entry_existing(&mut self, key: K) -> &mut V {
+        self.occupied_entry_unchecked(key).into_mut()
+    }
+
+    #[inline]
+    unsafe fn occupied_entry_unchecked(
+        &mut self,
+        key: K,
+    ) -> OccupiedEntry<'_, K, V, S> {
+        match self.entry(key) {
+            Entry::Occupied(entry) => entry,
+            Entry::Vacant(_) => unreachable(),
+        }
+    }
+}
+
+impl<T, S> UnsafeHashSet<T> for HashSet<T, S>
+    where T: Eq + Hash, S: BuildHasher
+{
+    #[inline]
+    unsafe fn get_existing<Q>(&self, value: &Q) -> &T
+        where T: Borrow<Q>, Q: Hash + Eq + ?Sized
+    {
+        self.get(value).unsafe_unwrap()
+    }
+}
+
+/// Unchecked raw-entry lookups on a `hashbrown::HashMap` with a precomputed
+/// hash.
+pub trait UnsafeRawEntry<K, V> {
+    /// Returns the key-value pair for `key` using its precomputed `hash`,
+    /// assuming that it is present.
+    ///
+    /// # Safety
+    ///
+    /// `self` must contain `key`, and `hash` must be the hash of `key` under
+    /// the map's hasher. Only the former is checked in debug builds; a wrong
+    /// hash is reported as a missing key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::hash::BuildHasher;
+    /// use unsafe_unwrap::interop::hashbrown::UnsafeRawEntry;
+    ///
+    /// let mut map = hashbrown::HashMap::with_hasher(
+    ///     std::collections::hash_map::RandomState::new(),
+    /// );
+    /// map.insert("key", 1);
+    /// let hash = map.hasher().hash_one("key");
+    /// unsafe {
+    ///     *map.get_hashed_existing_mut(hash, "key").1 += 1;
+    ///     assert_eq!(map.get_hashed_existing(hash, "key"), (&"key", &2));
+    /// }
+    /// ```
+    unsafe fn get_hashed_existing<Q>(&self, hash: u64, key: &Q) -> (&K, &V)
+        where Q: Equivalent<K> + ?Sized;
+
+    /// Returns mutable references to the key-value pair for `key` using its
+    /// precomputed `hash`, assuming that it is present.
+    ///
+    /// # Safety
+    ///
+    /// `self` must contain `key`, and `hash` must be the hash of `key` under
+    /// the map's hasher. Only the former is checked in debug builds; a wrong
+    /// hash is reported as a missing key.
+    unsafe fn get_hashed_existing_mut<Q>(
+        &mut self,
+        hash: u64,
+        key: &Q,
+    ) -> (&mut K, &mut V)
+        where Q: Equivalent<K> + ?Sized;
+}
+
+impl<K, V, S> UnsafeRawEntry<K, V> for HashMap<K, V, S> {
+    #[inline]
+    unsafe fn get_hashed_existing<Q>(&self, hash: u64, key: &Q) -> (&K, &V)
+        where Q: Equivalent<K> + ?Sized
+    {
+        self.raw_entry().from_key_hashed_nocheck(hash, key).unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn get_hashed_existing_mut<Q>(
+        &mut self,
+        hash: u64,
+        key: &Q,
+    ) -> (&mut K, &mut V)
+        where Q: Equivalent<K> + ?Sized
+    {
+        match self.raw_entry_mut().from_key_hashed_nocheck(hash, key) {
+            RawEntryMut::Occupied(entry) => entry.into_key_value(),
+            RawEntryMut::Vacant(_) => unreachable(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+    use core::hash::{BuildHasherDefault, Hasher};
+
+    /// FNV-1a, so that the tests don't depend on `std` or a default hasher.
+    struct Fnv(u64);
+
+    impl Default for Fnv {
+        fn default() -> Fnv {
+            Fnv(0xcbf2_9ce4_8422_2325)
+        }
+    }
+
+    impl Hasher for Fnv {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.0 = (self.0 ^ u64::from(b)).wrapping_mul(0x100_0000_01b3);
+            }
+        }
+    }
+
+    type Map<K, V> = HashMap<K, V, BuildHasherDefault<Fnv>>;
+    type Set<T> = HashSet<T, BuildHasherDefault<Fnv>>;
+
+    #[test]
+    fn map_success() {
+        let mut map = Map::default();
+        map.insert(String::from("a"), 1);
+        unsafe {
+            *map.get_existing_mut("a") += 1;
+            *map.entry_existing(String::from("a")) += 1;
+            assert_eq!(*map.get_existing("a"), 3);
+            let entry = map.occupied_entry_unchecked(String::from("a"));
+            assert_eq!(entry.remove(), 3);
+        }
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn map_get_failure() {
+        let map = Map::<String, u8>::default();
+        unsafe { map.get_existing("a") };
+    }
+
+    #[test]
+    #[should_panic]
+    fn map_entry_failure() {
+        let mut map = Map::<u8, u8>::default();
+        unsafe { map.entry_existing(0) };
+    }
+
+    #[test]
+    fn set_success() {
+        let mut set = Set::default();
+        set.insert(String::from("a"));
+        assert_eq!(unsafe { set.get_existing("a") }, "a");
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_failure() {
+        unsafe { Set::<String>::default().get_existing("a") };
+    }
+
+    #[test]
+    fn raw_entry_success() {
+        let mut map = Map::default();
+        map.insert(7u32, 'a');
+        let hash = map.hasher().hash_one(7u32);
+        unsafe {
+            *map.get_hashed_existing_mut(hash, &7).1 = 'b';
+            assert_eq!(map.get_hashed_existing(hash, &7), (&7, &'b'));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn raw_entry_failure() {
+        let map = Map::<u32, char>::default();
+        let hash = map.hasher().hash_one(7u32);
+        unsafe { map.get_hashed_existing(hash, &7) };
+    }
+}
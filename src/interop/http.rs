@@ -0,0 +1,103 @@
+//! Unchecked header and URI construction with [`http`](https://docs.rs/http).
+//!
+//! These are intended for proxies that copy values between messages after
+//! they were validated on receipt.
+
+use core::convert::TryFrom;
+
+use http::header::{HeaderName, HeaderValue};
+use http::Uri;
+
+use UnsafeUnwrap;
+
+/// Creates a header value from `s` without checking for invalid characters.
+///
+/// # Safety
+///
+/// `HeaderValue::from_str(s)` must be `Ok`: `s` must only contain visible
+/// ASCII characters, spaces, and tabs. This is checked in debug builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::interop::http::header_value_from_str_unchecked;
+///
+/// let value = unsafe { header_value_from_str_unchecked("text/html") };
+/// assert_eq!(value, "text/html");
+/// ```
+#[inline]
+pub unsafe fn header_value_from_str_unchecked(s: &str) -> HeaderValue {
+    HeaderValue::from_str(s).unsafe_unwrap()
+}
+
+/// Creates a header value from `bytes` without checking for invalid bytes.
+///
+/// # Safety
+///
+/// `HeaderValue::from_bytes(bytes)` must be `Ok`. This is checked in debug
+/// builds.
+#[inline]
+pub unsafe fn header_value_from_bytes_unchecked(bytes: &[u8]) -> HeaderValue {
+    HeaderValue::from_bytes(bytes).unsafe_unwrap()
+}
+
+/// Creates a header name from `bytes` without checking for invalid bytes.
+///
+/// # Safety
+///
+/// `HeaderName::from_bytes(bytes)` must be `Ok`. This is checked in debug
+/// builds.
+#[inline]
+pub unsafe fn header_name_from_bytes_unchecked(bytes: &[u8]) -> HeaderName {
+    HeaderName::from_bytes(bytes).unsafe_unwrap()
+}
+
+/// Parses a URI from `s`, assuming that it is well-formed.
+///
+/// # Safety
+///
+/// `Uri::try_from(s)` must be `Ok`. This is checked in debug builds.
+#[inline]
+pub unsafe fn uri_from_str_unchecked(s: &str) -> Uri {
+    Uri::try_from(s).unsafe_unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_success() {
+        unsafe {
+            let name = header_name_from_bytes_unchecked(b"Content-Type");
+            assert_eq!(name, http::header::CONTENT_TYPE);
+            let value = header_value_from_bytes_unchecked(b"text/plain");
+            assert_eq!(value, header_value_from_str_unchecked("text/plain"));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn header_name_failure() {
+        unsafe { header_name_from_bytes_unchecked(b"Content Type") };
+    }
+
+    #[test]
+    #[should_panic]
+    fn header_value_failure() {
+        unsafe { header_value_from_str_unchecked("line\nbreak") };
+    }
+
+    #[test]
+    fn uri_success() {
+        let uri = unsafe { uri_from_str_unchecked("https://example.com/a?b") };
+        assert_eq!(uri.host(), Some("example.com"));
+        assert_eq!(uri.path(), "/a");
+    }
+
+    #[test]
+    #[should_panic]
+    fn uri_failure() {
+        unsafe { uri_from_str_unchecked("http://[::1") };
+    }
+}
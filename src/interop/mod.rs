@@ -0,0 +1,36 @@
+//! Unchecked operations on types from third-party crates.
+//!
+//! Each module is enabled by the Cargo feature of the same name.
+
+#[cfg(feature = "anyhow")]
+pub mod anyhow;
+#[cfg(feature = "bytemuck")]
+pub mod bytemuck;
+#[cfg(feature = "bytes")]
+pub mod bytes;
+#[cfg(feature = "chrono")]
+pub mod chrono;
+#[cfg(feature = "crossbeam")]
+pub mod crossbeam;
+#[cfg(feature = "dashmap")]
+pub mod dashmap;
+#[cfg(feature = "hashbrown")]
+pub mod hashbrown;
+#[cfg(feature = "http")]
+pub mod http;
+#[cfg(feature = "nb")]
+pub mod nb;
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+#[cfg(feature = "nom")]
+pub mod nom;
+#[cfg(feature = "rayon")]
+pub mod rayon;
+#[cfg(feature = "spin")]
+pub mod spin;
+#[cfg(feature = "time")]
+pub mod time;
+#[cfg(feature = "uuid")]
+pub mod uuid;
+#[cfg(feature = "zerocopy")]
+pub mod zerocopy;
@@ -0,0 +1,91 @@
+//! Unchecked readiness of non-blocking results with [`nb`](https://docs.rs/nb).
+//!
+//! These are intended for drivers that poll a status flag before starting an
+//! operation, which makes `WouldBlock` impossible by construction.
+
+use nb::Error;
+
+use {UnsafeUnwrap, unreachable};
+
+/// Unchecked unwrapping of an `nb::Result`.
+pub trait UnsafeNbResult<T, E> {
+    /// Converts `self` into a plain `Result`, assuming that it is not
+    /// `WouldBlock`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must not be `Err(WouldBlock)`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::interop::nb::UnsafeNbResult;
+    ///
+    /// fn read(ready: bool) -> nb::Result<u8, ()> {
+    ///     if ready { Ok(0x2a) } else { Err(nb::Error::WouldBlock) }
+    /// }
+    ///
+    /// // The status register said that a byte is available.
+    /// let byte = unsafe { read(true).unwrap_ready_unchecked() };
+    /// assert_eq!(byte, Ok(0x2a));
+    /// ```
+    unsafe fn unwrap_ready_unchecked(self) -> Result<T, E>;
+
+    /// Returns the contained `Ok` value, assuming that `self` is neither
+    /// `WouldBlock` nor another error.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ok`. This is checked in debug builds.
+    unsafe fn unwrap_ok_unchecked(self) -> T;
+}
+
+impl<T, E> UnsafeNbResult<T, E> for nb::Result<T, E> {
+    #[inline]
+    unsafe fn unwrap_ready_unchecked(self) -> Result<T, E> {
+        match self {
+            Ok(x) => Ok(x),
+            Err(Error::Other(e)) => Err(e),
+            Err(Error::WouldBlock) => unreachable(),
+        }
+    }
+
+    #[inline]
+    unsafe fn unwrap_ok_unchecked(self) -> T {
+        self.unsafe_unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ready_success() {
+        let other: nb::Result<u8, char> = Err(Error::Other('e'));
+        unsafe {
+            assert_eq!(Ok::<u8, Error<()>>(1).unwrap_ready_unchecked(), Ok(1));
+            assert_eq!(other.unwrap_ready_unchecked(), Err('e'));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn ready_failure() {
+        let blocked: nb::Result<u8, ()> = Err(Error::WouldBlock);
+        let _ = unsafe { blocked.unwrap_ready_unchecked() };
+    }
+
+    #[test]
+    fn ok_success() {
+        let ok: nb::Result<u8, ()> = Ok(1);
+        assert_eq!(unsafe { ok.unwrap_ok_unchecked() }, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ok_failure() {
+        let other: nb::Result<u8, ()> = Err(Error::Other(()));
+        unsafe { other.unwrap_ok_unchecked() };
+    }
+}
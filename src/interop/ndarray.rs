@@ -0,0 +1,63 @@
+//! Unchecked multi-dimensional indexing with
+//! [`ndarray`](https://docs.rs/ndarray).
+//!
+//! This implements the indexing traits of [`index`](../../index/index.html)
+//! for `ArrayRef`, which every owned array and view dereferences to. Unlike
+//! `uget`, out-of-bounds indices go through this crate's failure policy.
+
+use ndarray::{ArrayRef, Dimension, NdIndex};
+
+use index::{UnsafeIndex, UnsafeIndexMut};
+use UnsafeUnwrap;
+
+impl<A, D, I> UnsafeIndex<I> for ArrayRef<A, D>
+    where D: Dimension, I: NdIndex<D>
+{
+    type Output = A;
+
+    #[inline]
+    unsafe fn index_unchecked(&self, index: I) -> &A {
+        self.get(index).unsafe_unwrap()
+    }
+}
+
+impl<A, D, I> UnsafeIndexMut<I> for ArrayRef<A, D>
+    where D: Dimension, I: NdIndex<D>
+{
+    #[inline]
+    unsafe fn index_unchecked_mut(&mut self, index: I) -> &mut A {
+        self.get_mut(index).unsafe_unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array2;
+
+    #[test]
+    fn array_success() {
+        let mut grid = Array2::<u8>::zeros((2, 3));
+        unsafe {
+            *grid.index_unchecked_mut((1, 2)) = 5;
+            assert_eq!(*grid.index_unchecked([1, 2]), 5);
+            let view = grid.view();
+            let [a, b] = view.index_many_unchecked([(0, 0), (1, 2)]);
+            assert_eq!((*a, *b), (0, 5));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn array_failure() {
+        let grid = Array2::<u8>::zeros((2, 3));
+        unsafe { grid.index_unchecked((2, 0)) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn array_mut_failure() {
+        let mut grid = Array2::<u8>::zeros((2, 3));
+        unsafe { grid.index_unchecked_mut((0, 3)) };
+    }
+}
@@ -0,0 +1,89 @@
+//! Unchecked parse results with [`nom`](https://docs.rs/nom).
+//!
+//! These are intended for grammars that are known to accept their input, such
+//! as configuration that was validated against a schema beforehand.
+
+use nom::{IResult, Input};
+
+use {UnsafeUnwrap, assume};
+
+/// Unchecked extraction of the output of an `IResult`.
+pub trait UnsafeIResult<I, O> {
+    /// Returns the parsed output, assuming that parsing succeeded, and
+    /// discards the remaining input.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ok`. This is checked in debug builds.
+    unsafe fn output_unchecked(self) -> O;
+
+    /// Returns the parsed output, assuming that parsing succeeded and
+    /// consumed all of the input.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ok` with empty remaining input. This is checked in
+    /// debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::interop::nom::UnsafeIResult;
+    ///
+    /// // The schema only allows decimal digits here.
+    /// let digits: nom::IResult<&str, &str> =
+    ///     nom::character::complete::digit1("8080");
+    /// let digits = unsafe { digits.complete_output_unchecked() };
+    /// assert_eq!(digits, "8080");
+    /// ```
+    unsafe fn complete_output_unchecked(self) -> O
+        where I: Input;
+}
+
+impl<I, O, E> UnsafeIResult<I, O> for IResult<I, O, E> {
+    #[inline]
+    unsafe fn output_unchecked(self) -> O {
+        self.unsafe_unwrap().1
+    }
+
+    #[inline]
+    unsafe fn complete_output_unchecked(self) -> O
+        where I: Input
+    {
+        let (rest, output) = self.unsafe_unwrap();
+        assume(rest.input_len() == 0);
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::character::complete::digit1;
+
+    #[test]
+    fn output_success() {
+        let parsed: IResult<&str, &str> = digit1("12ab");
+        assert_eq!(unsafe { parsed.output_unchecked() }, "12");
+    }
+
+    #[test]
+    #[should_panic]
+    fn output_failure() {
+        let parsed: IResult<&str, &str> = digit1("ab");
+        unsafe { parsed.output_unchecked() };
+    }
+
+    #[test]
+    fn complete_output_success() {
+        let parsed: IResult<&str, &str> = digit1("12");
+        assert_eq!(unsafe { parsed.complete_output_unchecked() }, "12");
+    }
+
+    #[test]
+    #[should_panic]
+    fn complete_output_failure() {
+        let parsed: IResult<&str, &str> = digit1("12ab");
+        unsafe { parsed.complete_output_unchecked() };
+    }
+}
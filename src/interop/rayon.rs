@@ -0,0 +1,110 @@
+//! Unchecked parallel iterator adapters with [`rayon`](https://docs.rs/rayon).
+//!
+//! These mirror the sequential adapters of
+//! [`UnsafeIterator`](../../iter/trait.UnsafeIterator.html).
+
+use rayon::iter::{FromParallelIterator, Map, ParallelIterator};
+
+use UnsafeUnwrapExt;
+
+/// A parallel iterator that unwraps the elements of another without
+/// checking.
+///
+/// This is indexed whenever `I` is. Created by
+/// [`UnsafeParallelIterator::unsafe_unwrapped`].
+///
+/// [`UnsafeParallelIterator::unsafe_unwrapped`]: trait.UnsafeParallelIterator.html#method.unsafe_unwrapped
+pub type UnsafeUnwrapped<I> =
+    Map<I, fn(Item<I>) -> <Item<I> as UnsafeUnwrapExt>::Owned>;
+
+type Item<I> = <I as ParallelIterator>::Item;
+
+/// Parallel iterator adapters and consumers that assume preconditions which
+/// would otherwise be checked per element.
+///
+/// This is implemented for every `ParallelIterator`.
+pub trait UnsafeParallelIterator: ParallelIterator {
+    /// Unwraps every element without checking, such as the `Ok` values of a
+    /// parallel iterator over `Result`s that were validated upfront.
+    ///
+    /// # Safety
+    ///
+    /// Every element must hold a value, as for `unsafe_unwrap()`. This is
+    /// checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// extern crate rayon;
+    /// extern crate unsafe_unwrap;
+    ///
+    /// use rayon::prelude::*;
+    /// use unsafe_unwrap::interop::rayon::UnsafeParallelIterator;
+    ///
+    /// let fields = ["1", "22", "333"];
+    /// let total: u32 = unsafe {
+    ///     fields.par_iter().map(|f| f.parse::<u32>()).unsafe_unwrapped()
+    /// }.sum();
+    /// assert_eq!(total, 356);
+    /// ```
+    #[inline]
+    unsafe fn unsafe_unwrapped(self) -> UnsafeUnwrapped<Self>
+        where Self::Item: UnsafeUnwrapExt,
+              <Self::Item as UnsafeUnwrapExt>::Owned: Send
+    {
+        self.map(unwrap_owned::<Self::Item>)
+    }
+
+    /// Collects the unwrapped elements into `C` without checking, such as
+    /// the `Ok` values of `Result`s that were validated upfront.
+    ///
+    /// # Safety
+    ///
+    /// Every element must hold a value, as for `unsafe_unwrap()`. This is
+    /// checked in debug builds.
+    #[inline]
+    unsafe fn collect_unchecked<C>(self) -> C
+        where Self::Item: UnsafeUnwrapExt,
+              <Self::Item as UnsafeUnwrapExt>::Owned: Send,
+              C: FromParallelIterator<<Self::Item as UnsafeUnwrapExt>::Owned>
+    {
+        self.unsafe_unwrapped().collect()
+    }
+}
+
+impl<I: ParallelIterator> UnsafeParallelIterator for I {}
+
+#[inline]
+fn unwrap_owned<X: UnsafeUnwrapExt>(x: X) -> X::Owned {
+    // Upheld by the caller of `unsafe_unwrapped`.
+    unsafe { x.unsafe_unwrap_owned() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rayon::iter::{IndexedParallelIterator, IntoParallelIterator};
+    use std::vec::Vec;
+
+    #[test]
+    fn unsafe_unwrapped_success() {
+        let items: Vec<_> = (0..100).map(Some).collect();
+        let unwrapped = unsafe { items.into_par_iter().unsafe_unwrapped() };
+        assert_eq!(unwrapped.len(), 100);
+        assert_eq!(unwrapped.sum::<i32>(), 4950);
+    }
+
+    #[test]
+    fn collect_success() {
+        let items: Vec<Result<_, ()>> = (0..100).map(Ok).collect();
+        let v: Vec<i32> = unsafe { items.into_par_iter().collect_unchecked() };
+        assert!(v.into_iter().eq(0..100));
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsafe_unwrapped_failure() {
+        let items: Vec<_> = (0..100).map(|x| Some(x).filter(|&x| x != 50)).collect();
+        unsafe { items.into_par_iter().unsafe_unwrapped().count() };
+    }
+}
@@ -0,0 +1,93 @@
+//! Unchecked lock operations with [`spin`](https://docs.rs/spin).
+//!
+//! This implements the lock traits of [`sync`](../../sync/index.html) without
+//! requiring `std`.
+
+use spin::mutex::{Mutex, MutexGuard};
+use spin::once::Once;
+use spin::rwlock::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use sync::{UnsafeMutex, UnsafeOnce, UnsafeRwLock};
+use UnsafeUnwrap;
+
+impl<T: ?Sized, R> UnsafeMutex for Mutex<T, R> {
+    type Guard<'a> = MutexGuard<'a, T> where Self: 'a;
+
+    #[inline]
+    unsafe fn try_lock_unchecked(&self) -> MutexGuard<'_, T> {
+        self.try_lock().unsafe_unwrap()
+    }
+}
+
+impl<T: ?Sized, R> UnsafeRwLock for RwLock<T, R> {
+    type ReadGuard<'a> = RwLockReadGuard<'a, T> where Self: 'a;
+    type WriteGuard<'a> = RwLockWriteGuard<'a, T, R> where Self: 'a;
+
+    #[inline]
+    unsafe fn try_read_unchecked(&self) -> RwLockReadGuard<'_, T> {
+        self.try_read().unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn try_write_unchecked(&self) -> RwLockWriteGuard<'_, T, R> {
+        self.try_write().unsafe_unwrap()
+    }
+}
+
+impl<T, R> UnsafeOnce for Once<T, R> {
+    type Value = T;
+
+    #[inline]
+    unsafe fn get_init_unchecked(&self) -> &T {
+        self.get().unsafe_unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutex_success() {
+        let mutex = Mutex::<_>::new(1);
+        unsafe { *mutex.try_lock_unchecked() += 1 };
+        assert_eq!(*mutex.lock(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mutex_failure() {
+        let mutex = Mutex::<_>::new(1);
+        let _guard = mutex.lock();
+        drop(unsafe { mutex.try_lock_unchecked() });
+    }
+
+    #[test]
+    fn rw_lock_success() {
+        let lock = RwLock::<_>::new(1);
+        unsafe {
+            *lock.try_write_unchecked() += 1;
+            assert_eq!(*lock.try_read_unchecked(), 2);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn rw_lock_failure() {
+        let lock = RwLock::<_>::new(1);
+        let _guard = lock.read();
+        drop(unsafe { lock.try_write_unchecked() });
+    }
+
+    #[test]
+    fn once_success() {
+        let once = Once::<_>::initialized(1);
+        assert_eq!(unsafe { *once.get_init_unchecked() }, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn once_failure() {
+        unsafe { Once::<u8>::new().get_init_unchecked() };
+    }
+}
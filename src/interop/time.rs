@@ -0,0 +1,140 @@
+//! Unchecked date and time construction with [`time`](https://docs.rs/time).
+//!
+//! These are intended for reconstructing timestamps from fields that were
+//! validated when they were first recorded.
+
+use time_crate::parsing::Parsable;
+use time_crate::{Date, Month, OffsetDateTime, Time};
+
+use UnsafeUnwrap;
+
+/// Creates a date from the year, month, and day without checking them.
+///
+/// # Safety
+///
+/// `Date::from_calendar_date(year, month, day)` must be `Ok`. This is checked
+/// in debug builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::interop::time::date_from_calendar_date_unchecked;
+///
+/// let date = unsafe {
+///     date_from_calendar_date_unchecked(2024, time::Month::February, 29)
+/// };
+/// assert_eq!(date.ordinal(), 60);
+/// ```
+#[inline]
+pub unsafe fn date_from_calendar_date_unchecked(
+    year: i32,
+    month: Month,
+    day: u8,
+) -> Date {
+    Date::from_calendar_date(year, month, day).unsafe_unwrap()
+}
+
+/// Creates a time from the hour, minute, and second without checking them.
+///
+/// # Safety
+///
+/// `Time::from_hms(hour, minute, second)` must be `Ok`. This is checked in
+/// debug builds.
+#[inline]
+pub unsafe fn time_from_hms_unchecked(
+    hour: u8,
+    minute: u8,
+    second: u8,
+) -> Time {
+    Time::from_hms(hour, minute, second).unsafe_unwrap()
+}
+
+/// Creates a time from the hour, minute, second, and nanosecond without
+/// checking them.
+///
+/// # Safety
+///
+/// `Time::from_hms_nano(hour, minute, second, nanosecond)` must be `Ok`. This
+/// is checked in debug builds.
+#[inline]
+pub unsafe fn time_from_hms_nano_unchecked(
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+) -> Time {
+    Time::from_hms_nano(hour, minute, second, nanosecond).unsafe_unwrap()
+}
+
+/// Creates a UTC date and time from a Unix timestamp without checking its
+/// range.
+///
+/// # Safety
+///
+/// `OffsetDateTime::from_unix_timestamp(timestamp)` must be `Ok`. This is
+/// checked in debug builds.
+#[inline]
+pub unsafe fn offset_datetime_from_unix_timestamp_unchecked(
+    timestamp: i64,
+) -> OffsetDateTime {
+    OffsetDateTime::from_unix_timestamp(timestamp).unsafe_unwrap()
+}
+
+/// Parses a date and time with an offset from `input` in the format
+/// `description`, assuming that it matches.
+///
+/// # Safety
+///
+/// `OffsetDateTime::parse(input, description)` must be `Ok`. This is checked
+/// in debug builds.
+#[inline]
+pub unsafe fn offset_datetime_parse_unchecked<F>(
+    input: &str,
+    description: &F,
+) -> OffsetDateTime
+    where F: Parsable + ?Sized
+{
+    OffsetDateTime::parse(input, description).unsafe_unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time_crate::format_description::well_known::Rfc3339;
+
+    #[test]
+    fn datetime_success() {
+        unsafe {
+            let date = date_from_calendar_date_unchecked(2001, Month::September, 9);
+            let time = time_from_hms_unchecked(1, 46, 40);
+            let parsed = offset_datetime_parse_unchecked(
+                "2001-09-09T01:46:40.000000005Z",
+                &Rfc3339,
+            );
+            assert_eq!(parsed.date(), date);
+            assert_eq!(parsed.time(), time_from_hms_nano_unchecked(1, 46, 40, 5));
+            let utc = offset_datetime_from_unix_timestamp_unchecked(1_000_000_000);
+            assert_eq!((utc.date(), utc.time()), (date, time));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn date_failure() {
+        unsafe { date_from_calendar_date_unchecked(2023, Month::February, 29) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn time_failure() {
+        unsafe { time_from_hms_unchecked(24, 0, 0) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn parse_failure() {
+        unsafe {
+            offset_datetime_parse_unchecked("2001-09-09T01:46:40", &Rfc3339);
+        }
+    }
+}
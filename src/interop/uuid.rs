@@ -0,0 +1,71 @@
+//! Unchecked UUID construction with [`uuid`](https://docs.rs/uuid).
+
+use uuid::Uuid;
+
+use UnsafeUnwrap;
+
+/// Parses a UUID from `s`, assuming that it is well-formed.
+///
+/// Any format accepted by `Uuid::parse_str` is supported, such as hyphenated
+/// or simple.
+///
+/// # Safety
+///
+/// `Uuid::parse_str(s)` must be `Ok`. This is checked in debug builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::interop::uuid::uuid_from_str_unchecked;
+///
+/// let id = unsafe {
+///     uuid_from_str_unchecked("67e55044-10b1-426f-9247-bb680e5fe0c8")
+/// };
+/// assert_eq!(id.get_version_num(), 4);
+/// ```
+#[inline]
+pub unsafe fn uuid_from_str_unchecked(s: &str) -> Uuid {
+    Uuid::parse_str(s).unsafe_unwrap()
+}
+
+/// Creates a UUID from `bytes`, assuming that it is exactly 16 bytes long.
+///
+/// # Safety
+///
+/// `bytes.len()` must be 16. This is checked in debug builds.
+#[inline]
+pub unsafe fn uuid_from_slice_unchecked(bytes: &[u8]) -> Uuid {
+    Uuid::from_slice(bytes).unsafe_unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uuid_success() {
+        let bytes = [
+            0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f,
+            0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0, 0xc8,
+        ];
+        unsafe {
+            let simple = "67e5504410b1426f9247bb680e5fe0c8";
+            assert_eq!(
+                uuid_from_str_unchecked(simple),
+                uuid_from_slice_unchecked(&bytes),
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn uuid_from_str_failure() {
+        unsafe { uuid_from_str_unchecked("67e55044-10b1-426f-9247") };
+    }
+
+    #[test]
+    #[should_panic]
+    fn uuid_from_slice_failure() {
+        unsafe { uuid_from_slice_unchecked(&[0; 15]) };
+    }
+}
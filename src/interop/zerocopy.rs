@@ -0,0 +1,104 @@
+//! Unchecked byte reinterpretation with [`zerocopy`](https://docs.rs/zerocopy).
+//!
+//! Each function runs the corresponding zerocopy validation in debug builds
+//! and assumes that it succeeds in optimized builds, so size, alignment, and
+//! validity checks are paid once rather than for every field view.
+
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, TryFromBytes};
+
+use UnsafeUnwrap;
+
+/// Reinterprets `bytes` as a `&T` without checking its size or alignment.
+///
+/// # Safety
+///
+/// `T::ref_from_bytes(bytes)` must be `Ok`. This is checked in debug builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::interop::zerocopy::ref_from_bytes_unchecked;
+///
+/// let words = [1u16, 2, 3];
+/// let bytes = zerocopy::IntoBytes::as_bytes(&words);
+/// let view: &[u16] = unsafe { ref_from_bytes_unchecked(bytes) };
+/// assert_eq!(view, [1, 2, 3]);
+/// ```
+#[inline]
+pub unsafe fn ref_from_bytes_unchecked<T>(bytes: &[u8]) -> &T
+    where T: FromBytes + KnownLayout + Immutable + ?Sized
+{
+    T::ref_from_bytes(bytes).unsafe_unwrap()
+}
+
+/// Reinterprets `bytes` as a `&mut T` without checking its size or alignment.
+///
+/// # Safety
+///
+/// `T::mut_from_bytes(bytes)` must be `Ok`. This is checked in debug builds.
+#[inline]
+pub unsafe fn mut_from_bytes_unchecked<T>(bytes: &mut [u8]) -> &mut T
+    where T: FromBytes + IntoBytes + KnownLayout + ?Sized
+{
+    T::mut_from_bytes(bytes).unsafe_unwrap()
+}
+
+/// Reinterprets `bytes` as a `&T` without checking its size, alignment, or
+/// validity.
+///
+/// # Safety
+///
+/// `T::try_ref_from_bytes(bytes)` must be `Ok`: `bytes` must also be a valid
+/// `T`. This is checked in debug builds.
+#[inline]
+pub unsafe fn try_ref_from_bytes_unchecked<T>(bytes: &[u8]) -> &T
+    where T: TryFromBytes + KnownLayout + Immutable + ?Sized
+{
+    T::try_ref_from_bytes(bytes).unsafe_unwrap()
+}
+
+/// Reads a `T` from possibly unaligned `bytes` without checking its size.
+///
+/// # Safety
+///
+/// `bytes` must be exactly `size_of::<T>()` long. This is checked in debug
+/// builds.
+#[inline]
+pub unsafe fn read_from_bytes_unchecked<T: FromBytes>(bytes: &[u8]) -> T {
+    T::read_from_bytes(bytes).unsafe_unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ref_from_bytes_success() {
+        let mut words = [1u32, 2];
+        unsafe {
+            *mut_from_bytes_unchecked::<u32>(&mut words.as_mut_bytes()[4..]) = 3;
+            let view: &[u32] = ref_from_bytes_unchecked(words.as_bytes());
+            assert_eq!(view, [1, 3]);
+            let flag: &bool = try_ref_from_bytes_unchecked(&[1]);
+            assert!(*flag);
+            let unaligned = &words.as_bytes()[1..5];
+            let expected = u32::from_ne_bytes([
+                unaligned[0], unaligned[1], unaligned[2], unaligned[3],
+            ]);
+            assert_eq!(read_from_bytes_unchecked::<u32>(unaligned), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn ref_from_bytes_failure() {
+        let words = [1u32, 2];
+        unsafe { ref_from_bytes_unchecked::<u32>(&words.as_bytes()[..3]) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn try_ref_from_bytes_failure() {
+        unsafe { try_ref_from_bytes_unchecked::<bool>(&[2]) };
+    }
+}
@@ -0,0 +1,94 @@
+//! Unchecked I/O buffer unwrapping.
+//!
+//! These are intended for writers whose sinks cannot fail, such as `Vec<u8>`.
+//! This module requires the `std` feature.
+
+use std::io::{BufWriter, LineWriter, Write};
+
+use UnsafeUnwrap;
+
+/// Unwrapping of a buffered writer whose final flush cannot fail.
+pub trait UnsafeIntoInner<W> {
+    /// Flushes the buffer and returns the underlying writer, assuming that
+    /// flushing does not fail.
+    ///
+    /// # Safety
+    ///
+    /// `self.into_inner()` must be `Ok`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::{BufWriter, Write};
+    /// use unsafe_unwrap::io::UnsafeIntoInner;
+    ///
+    /// let mut out = BufWriter::new(Vec::new());
+    /// out.write_all(b"done").unwrap();
+    /// // Writing to a `Vec` cannot fail.
+    /// assert_eq!(unsafe { out.into_inner_unchecked() }, b"done");
+    /// ```
+    unsafe fn into_inner_unchecked(self) -> W;
+}
+
+impl<W: Write> UnsafeIntoInner<W> for BufWriter<W> {
+    #[inline]
+    unsafe fn into_inner_unchecked(self) -> W {
+        self.into_inner().unsafe_unwrap()
+    }
+}
+
+impl<W: Write> UnsafeIntoInner<W> for LineWriter<W> {
+    #[inline]
+    unsafe fn into_inner_unchecked(self) -> W {
+        self.into_inner().unsafe_unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+    use std::vec::Vec;
+
+    struct Broken;
+
+    impl Write for Broken {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::Other.into())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn buf_writer_success() {
+        let mut out = BufWriter::new(Vec::new());
+        out.write_all(b"abc").unwrap();
+        assert_eq!(unsafe { out.into_inner_unchecked() }, b"abc");
+    }
+
+    #[test]
+    #[should_panic]
+    fn buf_writer_failure() {
+        let mut out = BufWriter::new(Broken);
+        out.write_all(b"abc").unwrap();
+        unsafe { out.into_inner_unchecked() };
+    }
+
+    #[test]
+    fn line_writer_success() {
+        let mut out = LineWriter::new(Vec::new());
+        out.write_all(b"a\nb").unwrap();
+        assert_eq!(unsafe { out.into_inner_unchecked() }, b"a\nb");
+    }
+
+    #[test]
+    #[should_panic]
+    fn line_writer_failure() {
+        let mut out = LineWriter::new(Broken);
+        out.write_all(b"abc").unwrap();
+        unsafe { out.into_inner_unchecked() };
+    }
+}
@@ -0,0 +1,426 @@
+//! Unchecked operations on iterators.
+
+use core::iter::{FromIterator, FusedIterator};
+
+use {CHECKED, UnsafeUnwrap, UnsafeUnwrapExt, unreachable};
+
+/// Iterator adapters and consumers that assume preconditions which the
+/// standard methods would otherwise check.
+///
+/// This is implemented for every `Iterator`.
+pub trait UnsafeIterator: Iterator {
+    /// Reduces the elements to a single one with `f`, assuming that there is
+    /// at least one element.
+    ///
+    /// Unlike `reduce`, this returns the result directly, without carrying an
+    /// `Option` through the accumulation.
+    ///
+    /// # Safety
+    ///
+    /// `self` must yield at least one element. This is checked in debug
+    /// builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::iter::UnsafeIterator;
+    ///
+    /// let group = [3, 9, 4];
+    /// let max = unsafe { group.iter().cloned().reduce_unchecked(u32::max) };
+    /// assert_eq!(max, 9);
+    /// ```
+    #[inline]
+    unsafe fn reduce_unchecked<F>(mut self, f: F) -> Self::Item
+        where Self: Sized, F: FnMut(Self::Item, Self::Item) -> Self::Item
+    {
+        let first = self.next().unsafe_unwrap();
+        self.fold(first, f)
+    }
+
+    /// Returns the `n`th element, assuming that there are more than `n`
+    /// elements remaining.
+    ///
+    /// # Safety
+    ///
+    /// `self` must yield more than `n` elements. This is checked in debug
+    /// builds, before any are consumed if `size_hint` shows that there are
+    /// too few.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::iter::UnsafeIterator;
+    ///
+    /// let row = "id,name,email";
+    /// let name = unsafe { row.split(',').nth_unchecked(1) };
+    /// assert_eq!(name, "name");
+    /// ```
+    #[inline]
+    unsafe fn nth_unchecked(&mut self, n: usize) -> Self::Item {
+        if CHECKED && self.size_hint().1.is_some_and(|upper| upper <= n) {
+            unreachable();
+        }
+        self.nth(n).unsafe_unwrap()
+    }
+
+    /// Zips `self` with `other`, assuming that both yield the same number of
+    /// elements.
+    ///
+    /// Unlike `zip`, only `self` is checked for exhaustion, and the adapter
+    /// is an `ExactSizeIterator` whenever `self` is.
+    ///
+    /// # Safety
+    ///
+    /// `self` and `other` must yield the same number of elements. This is
+    /// checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::iter::UnsafeIterator;
+    ///
+    /// let (a, b) = ([1.0, 2.0, 3.0], [4.0, 5.0, 6.0]);
+    /// let dot: f64 = unsafe { a.iter().zip_eq_unchecked(&b) }
+    ///     .map(|(x, y)| x * y)
+    ///     .sum();
+    /// assert_eq!(dot, 32.0);
+    /// ```
+    #[inline]
+    unsafe fn zip_eq_unchecked<U>(self, other: U) -> ZipEq<Self, U::IntoIter>
+        where Self: Sized, U: IntoIterator
+    {
+        let other = other.into_iter();
+        if CHECKED {
+            let (a, b) = (self.size_hint(), other.size_hint());
+            if a.1.is_some_and(|hi| hi < b.0) || b.1.is_some_and(|hi| hi < a.0) {
+                unreachable();
+            }
+        }
+        ZipEq { a: self, b: other }
+    }
+
+    /// Unwraps every element without checking, such as the `Ok` values of an
+    /// iterator over `Result`s that were validated upfront.
+    ///
+    /// # Safety
+    ///
+    /// Every element must hold a value, as for `unsafe_unwrap()`. This is
+    /// checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::iter::UnsafeIterator;
+    ///
+    /// let fields = ["1", "22", "333"];
+    /// let lens: Vec<u32> = unsafe {
+    ///     fields.iter().map(|f| f.parse::<u32>()).unsafe_unwrapped()
+    /// }.collect();
+    /// assert_eq!(lens, [1, 22, 333]);
+    /// ```
+    #[inline]
+    unsafe fn unsafe_unwrapped(self) -> UnsafeUnwrapped<Self>
+        where Self: Sized, Self::Item: UnsafeUnwrapExt
+    {
+        UnsafeUnwrapped { iter: self }
+    }
+
+    /// Collects the unwrapped elements into `C` without checking, such as
+    /// the `Ok` values of `Result`s that were validated upfront.
+    ///
+    /// # Safety
+    ///
+    /// Every element must hold a value, as for `unsafe_unwrap()`. This is
+    /// checked in debug builds.
+    #[inline]
+    unsafe fn collect_unchecked<C>(self) -> C
+        where Self: Sized,
+              Self::Item: UnsafeUnwrapExt,
+              C: FromIterator<<Self::Item as UnsafeUnwrapExt>::Owned>
+    {
+        self.unsafe_unwrapped().collect()
+    }
+
+    /// Returns the only element, assuming that there is exactly one.
+    ///
+    /// Unlike `exactly_one` from `itertools`, this neither checks for a
+    /// second element nor constructs an error value.
+    ///
+    /// # Safety
+    ///
+    /// `self` must yield exactly one element. This is checked in debug
+    /// builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::iter::UnsafeIterator;
+    ///
+    /// let users = [(1, "ana"), (2, "bo")];
+    /// let (_, name) = unsafe {
+    ///     users.iter().filter(|u| u.0 == 2).exactly_one_unchecked()
+    /// };
+    /// assert_eq!(*name, "bo");
+    /// ```
+    #[inline]
+    unsafe fn exactly_one_unchecked(mut self) -> Self::Item
+        where Self: Sized
+    {
+        let item = self.next().unsafe_unwrap();
+        if CHECKED && self.next().is_some() {
+            unreachable();
+        }
+        item
+    }
+
+    /// Returns the only element, if any, assuming that there is at most one.
+    ///
+    /// # Safety
+    ///
+    /// `self` must yield at most one element. This is checked in debug
+    /// builds.
+    #[inline]
+    unsafe fn at_most_one_unchecked(mut self) -> Option<Self::Item>
+        where Self: Sized
+    {
+        let item = self.next();
+        if CHECKED && item.is_some() && self.next().is_some() {
+            unreachable();
+        }
+        item
+    }
+}
+
+impl<I: Iterator + ?Sized> UnsafeIterator for I {}
+
+/// An iterator over pairs of elements from two iterators of equal length.
+///
+/// Created by [`UnsafeIterator::zip_eq_unchecked`].
+///
+/// [`UnsafeIterator::zip_eq_unchecked`]: trait.UnsafeIterator.html#method.zip_eq_unchecked
+#[derive(Clone, Debug)]
+pub struct ZipEq<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A: Iterator, B: Iterator> Iterator for ZipEq<A, B> {
+    type Item = (A::Item, B::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // Upheld by the caller of `zip_eq_unchecked`.
+        match self.a.next() {
+            Some(a) => Some((a, unsafe { self.b.next().unsafe_unwrap() })),
+            None => {
+                if CHECKED && self.b.next().is_some() {
+                    unsafe { unreachable() }
+                }
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.a.size_hint()
+    }
+}
+
+impl<A, B> DoubleEndedIterator for ZipEq<A, B>
+    where A: DoubleEndedIterator, B: DoubleEndedIterator
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.a.next_back() {
+            Some(a) => Some((a, unsafe { self.b.next_back().unsafe_unwrap() })),
+            None => {
+                if CHECKED && self.b.next_back().is_some() {
+                    unsafe { unreachable() }
+                }
+                None
+            }
+        }
+    }
+}
+
+impl<A: ExactSizeIterator, B: Iterator> ExactSizeIterator for ZipEq<A, B> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.a.len()
+    }
+}
+
+impl<A: FusedIterator, B: Iterator> FusedIterator for ZipEq<A, B> {}
+
+/// An iterator that unwraps the elements of another without checking.
+///
+/// Created by [`UnsafeIterator::unsafe_unwrapped`].
+///
+/// [`UnsafeIterator::unsafe_unwrapped`]: trait.UnsafeIterator.html#method.unsafe_unwrapped
+#[derive(Clone, Debug)]
+pub struct UnsafeUnwrapped<I> {
+    iter: I,
+}
+
+impl<I> Iterator for UnsafeUnwrapped<I>
+    where I: Iterator, I::Item: UnsafeUnwrapExt
+{
+    type Item = <I::Item as UnsafeUnwrapExt>::Owned;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // Upheld by the caller of `unsafe_unwrapped`.
+        self.iter.next().map(|x| unsafe { x.unsafe_unwrap_owned() })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for UnsafeUnwrapped<I>
+    where I: DoubleEndedIterator, I::Item: UnsafeUnwrapExt
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|x| unsafe { x.unsafe_unwrap_owned() })
+    }
+}
+
+impl<I> ExactSizeIterator for UnsafeUnwrapped<I>
+    where I: ExactSizeIterator, I::Item: UnsafeUnwrapExt
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I> FusedIterator for UnsafeUnwrapped<I>
+    where I: FusedIterator, I::Item: UnsafeUnwrapExt
+{}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reduce_success() {
+        unsafe {
+            assert_eq!((1..5).reduce_unchecked(|a, b| a * b), 24);
+            assert_eq!((7..8).reduce_unchecked(|_, _| unreachable!()), 7);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn reduce_failure() {
+        unsafe { (0..0).reduce_unchecked(|a, b| a + b) };
+    }
+
+    #[test]
+    fn nth_success() {
+        let mut iter = 0..10;
+        unsafe {
+            assert_eq!(iter.nth_unchecked(2), 2);
+            assert_eq!(iter.nth_unchecked(6), 9);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn nth_failure() {
+        unsafe { (0..3).nth_unchecked(3) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn nth_unknown_len_failure() {
+        let mut range = 0..3;
+        let mut iter = core::iter::from_fn(|| range.next());
+        unsafe { iter.nth_unchecked(3) };
+    }
+
+    #[test]
+    fn zip_eq_success() {
+        let (a, b) = ([1, 2, 3], [4, 5, 6]);
+        let zipped = unsafe { a.iter().zip_eq_unchecked(b.iter()) };
+        assert_eq!(zipped.len(), 3);
+        assert!(zipped.rev().eq([(&3, &6), (&2, &5), (&1, &4)]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zip_eq_failure() {
+        unsafe { (0..3).zip_eq_unchecked(0..2) };
+    }
+
+    #[test]
+    fn zip_eq_unknown_len_success() {
+        let mut range = 0..3;
+        let iter = core::iter::from_fn(|| range.next());
+        assert_eq!(unsafe { iter.zip_eq_unchecked(0..3) }.count(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zip_eq_unknown_len_failure() {
+        let mut range = 0..3;
+        let iter = core::iter::from_fn(|| range.next());
+        unsafe { iter.zip_eq_unchecked(0..2).count() };
+    }
+
+    #[test]
+    fn unsafe_unwrapped_success() {
+        let items = [Some(1), Some(2), Some(3)];
+        let unwrapped = unsafe { items.iter().cloned().unsafe_unwrapped() };
+        assert_eq!(unwrapped.len(), 3);
+        assert!(unwrapped.rev().eq([3, 2, 1]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsafe_unwrapped_failure() {
+        let items = [Ok(1), Err(())];
+        unsafe { items.iter().cloned().unsafe_unwrapped().count() };
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn collect_success() {
+        let items = [Ok::<_, ()>(1), Ok(2)];
+        let v: alloc::vec::Vec<_> = unsafe {
+            items.iter().cloned().collect_unchecked()
+        };
+        assert_eq!(v, [1, 2]);
+    }
+
+    #[test]
+    fn one_success() {
+        unsafe {
+            assert_eq!((3..4).exactly_one_unchecked(), 3);
+            assert_eq!((3..4).at_most_one_unchecked(), Some(3));
+            assert_eq!((3..3).at_most_one_unchecked(), None);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn exactly_one_none_failure() {
+        unsafe { (0..0).exactly_one_unchecked() };
+    }
+
+    #[test]
+    #[should_panic]
+    fn exactly_one_many_failure() {
+        unsafe { (0..2).exactly_one_unchecked() };
+    }
+
+    #[test]
+    #[should_panic]
+    fn at_most_one_failure() {
+        unsafe { (0..2).at_most_one_unchecked() };
+    }
+}
@@ -0,0 +1,96 @@
+//! Unchecked construction of memory layouts.
+//!
+//! These are intended for allocators that compute layouts from arithmetic
+//! that has already been validated.
+
+use core::alloc::Layout;
+
+use UnsafeUnwrap;
+
+/// Creates a layout from `size` and `align` without checking them.
+///
+/// # Safety
+///
+/// `Layout::from_size_align(size, align)` must be `Ok`: `align` must be a
+/// power of two and `size` rounded up to `align` must not exceed
+/// `isize::MAX`. This is checked in debug builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::layout::layout_unchecked;
+///
+/// let layout = unsafe { layout_unchecked(64, 16) };
+/// assert_eq!((layout.size(), layout.align()), (64, 16));
+/// ```
+#[inline]
+pub unsafe fn layout_unchecked(size: usize, align: usize) -> Layout {
+    Layout::from_size_align(size, align).unsafe_unwrap()
+}
+
+/// Creates the layout of `[T; n]` without checking for overflow.
+///
+/// # Safety
+///
+/// `Layout::array::<T>(n)` must be `Ok`. This is checked in debug builds.
+#[inline]
+pub unsafe fn layout_array_unchecked<T>(n: usize) -> Layout {
+    Layout::array::<T>(n).unsafe_unwrap()
+}
+
+/// Extends `layout` with `next`, returning the combined layout and the offset
+/// of `next` within it, without checking for overflow.
+///
+/// # Safety
+///
+/// `layout.extend(next)` must be `Ok`. This is checked in debug builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::alloc::Layout;
+/// use unsafe_unwrap::layout::layout_extend_unchecked;
+///
+/// let header = Layout::new::<u16>();
+/// let (layout, offset) = unsafe {
+///     layout_extend_unchecked(header, Layout::new::<u64>())
+/// };
+/// assert_eq!(offset, 8);
+/// ```
+#[inline]
+pub unsafe fn layout_extend_unchecked(
+    layout: Layout,
+    next: Layout,
+) -> (Layout, usize) {
+    layout.extend(next).unsafe_unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_success() {
+        unsafe {
+            assert_eq!(layout_unchecked(8, 4), Layout::new::<[u32; 2]>());
+            assert_eq!(layout_array_unchecked::<u32>(2), Layout::new::<[u32; 2]>());
+            let (layout, offset) = layout_extend_unchecked(
+                Layout::new::<u8>(),
+                Layout::new::<u32>(),
+            );
+            assert_eq!((layout.size(), offset), (8, 4));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn layout_failure() {
+        unsafe { layout_unchecked(8, 3) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn layout_array_failure() {
+        unsafe { layout_array_unchecked::<u64>(usize::MAX) };
+    }
+}
@@ -18,9 +18,197 @@
 //! let x = Some(20);
 //! let y = unsafe { x.unsafe_unwrap() };
 //! ```
+//!
+//! The traits of every module can be imported at once from `prelude`.
+//!
+//! # Layout
+//!
+//! The crate root holds `UnsafeUnwrap`, the traits built directly on it, and
+//! the failure path that every check goes through. Each other module covers
+//! one area, such as `option`, `slice`, or `sync`, and only builds on the
+//! root. The modules of `interop` cover third-party crates.
+//!
+//! # Cargo Features
+//!
+//! - `hints`, `iter`, `option`, `result`, `slice`, `str`, `sync`: enable the
+//!   modules of the same name. These only require `core` and are enabled by
+//!   default. `slice` implies `hints`.
+//! - `collections`: enables the `collections` module. Implies `alloc`.
+//! - `alloc`: enables unchecked operations on `alloc` types, such as `Cow`,
+//!   in the enabled modules.
+//! - `std`: enables unchecked operations on `std` types, such as `Instant`,
+//!   in the enabled modules. Implies `alloc`.
+//! - `macros`: enables `#[derive(UnsafeFromPrimitive)]` and the
+//!   `#[requires(cond)]` and `#[ensures(cond)]` contract attributes, which
+//!   check conditions on entry to and exit from an `unsafe fn` in debug builds
+//!   and assume them in optimized builds. Also enables `#[assume_unwraps]`,
+//!   which rewrites `.unwrap()` and `.expect("...")` calls within an
+//!   `unsafe fn` to be unchecked.
+//! - `anyhow`: enables assumed-type downcasting of `anyhow::Error` in
+//!   `interop`. Implies `alloc`.
+//! - `bytemuck`, `zerocopy`: enable unchecked byte reinterpretation in
+//!   `interop`, which validates in debug builds only.
+//! - `bytes`: enables unchecked `Buf` reads in `interop`.
+//! - `chrono`, `time`: enable unchecked date and time construction in
+//!   `interop`.
+//! - `crossbeam`: enables unchecked `crossbeam-queue` operations in
+//!   `interop`.
+//! - `dashmap`: enables unchecked `DashMap` lookups in `interop`. Implies
+//!   `std`.
+//! - `hashbrown`: implements the hash map and set traits of `collections` for
+//!   `hashbrown` collections, without `std`, and enables unchecked raw-entry
+//!   lookups in `interop`. Implies `collections`.
+//! - `http`: enables unchecked header and URI construction in `interop`.
+//! - `ndarray`: implements the indexing traits of `index` for `ndarray`
+//!   arrays.
+//! - `nb`: enables unchecked readiness of `nb::Result` values in `interop`.
+//! - `nom`: enables unchecked extraction of `nom` parse results in `interop`.
+//! - `rayon`: enables unchecked parallel iterator adapters in `interop`.
+//!   Implies `std`.
+//! - `spin`: implements the lock traits of `sync` for `spin` locks, without
+//!   `std`. Implies `sync`.
+//! - `uuid`: enables unchecked UUID parsing in `interop`.
+//! - `failure-hook`: makes debug-build failures call a user-defined
+//!   `unsafe_unwrap_failed` function instead of panicking, which must be
+//!   provided by the final binary:
+//!
+//!   ```ignore
+//!   #[no_mangle]
+//!   fn unsafe_unwrap_failed(file: &str, line: u32) -> ! {
+//!       // Route to a fault logger, reset, ...
+//!   }
+//!   ```
+//!
+//!   The location is that of the `unsafe_unwrap()` or
+//!   `unsafe_unwrap_because()` call; for other methods, it points into this
+//!   crate.
+//! - `inline-always`, `inline-never`: replace the `#[inline]` hint on the
+//!   `UnsafeUnwrap`, `UnsafeUnwrapExt`, and `UnsafeUnwrapDeep` impls, which
+//!   the rest of the crate is built on, with `#[inline(always)]` or
+//!   `#[inline(never)]`. The latter also outlines the failure path of debug
+//!   builds, reducing code size at the cost of a call per unwrap. These are
+//!   mutually exclusive.
+//! - `panic-lite`: makes debug-build failures panic with a static message
+//!   only, avoiding the formatting machinery on code-size-constrained targets.
+//! - `runtime-policy`: makes failures in optimized builds panic, as in debug
+//!   builds, if the `UNSAFE_UNWRAP_CHECKS` environment variable is `1` when
+//!   the first failure occurs, so that shipped binaries can be debugged
+//!   without a rebuild. The checks are kept in optimized builds, with only the
+//!   failure path outlined, and checks that would otherwise only run in debug
+//!   builds are not enabled. Implies `std`.
+//! - `strict`: deprecates `unsafe_unwrap()` in favor of
+//!   `unsafe_unwrap_because()`, so that every unchecked unwrap states its
+//!   invariant.
+//! - `verify`: checks invariants in optimized builds too, for use with
+//!   formal verifiers. Builds under Kani (`cfg(kani)`) do so regardless.
+//! - `wasm-trap`: makes debug-build failures on `wasm32` execute the
+//!   `unreachable` instruction instead of panicking. Optimized builds on
+//!   `wasm32` always do so.
+//! - `try_trait`: adds `UnsafeUnwrapTry`, implemented for every
+//!   `core::ops::Try` type. Requires a nightly compiler.
 
 #![no_std]
-#![cfg_attr(test, feature(test))]
+#![cfg_attr(feature = "try_trait", feature(try_trait_v2))]
+// The `strict` deprecation is aimed at callers, not at this implementation.
+#![cfg_attr(feature = "strict", allow(deprecated))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "macros")]
+extern crate unsafe_unwrap_macros;
+
+#[cfg(feature = "anyhow")]
+extern crate anyhow;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+#[cfg(feature = "bytes")]
+extern crate bytes;
+#[cfg(feature = "chrono")]
+extern crate chrono;
+// Renamed, since `time` is also a module of this crate.
+#[cfg(feature = "time")]
+extern crate time as time_crate;
+#[cfg(feature = "crossbeam")]
+extern crate crossbeam_queue;
+#[cfg(feature = "dashmap")]
+extern crate dashmap;
+#[cfg(feature = "hashbrown")]
+extern crate hashbrown;
+#[cfg(feature = "http")]
+extern crate http;
+#[cfg(feature = "nb")]
+extern crate nb;
+#[cfg(feature = "ndarray")]
+extern crate ndarray;
+#[cfg(feature = "nom")]
+extern crate nom;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "spin")]
+extern crate spin;
+#[cfg(feature = "uuid")]
+extern crate uuid;
+#[cfg(feature = "zerocopy")]
+extern crate zerocopy;
+
+// Allows macro output, which names `::unsafe_unwrap`, to be tested here.
+#[cfg(all(test, feature = "macros"))]
+extern crate self as unsafe_unwrap;
+
+#[cfg(feature = "macros")]
+pub use unsafe_unwrap_macros::{
+    UnsafeFromPrimitive, assume_unwraps, ensures, requires,
+};
+
+#[macro_use]
+mod macros;
+
+#[cfg(feature = "collections")]
+pub mod collections;
+#[cfg(feature = "std")]
+pub mod env;
+#[cfg(feature = "hints")]
+pub mod hints;
+pub mod index;
+pub mod interop;
+#[cfg(feature = "std")]
+pub mod io;
+#[cfg(feature = "iter")]
+pub mod iter;
+pub mod layout;
+pub mod net;
+pub mod never;
+pub mod num;
+#[cfg(feature = "option")]
+pub mod option;
+pub mod pin;
+pub mod prelude;
+#[cfg(feature = "std")]
+pub mod process;
+#[cfg(feature = "result")]
+pub mod result;
+#[cfg(feature = "slice")]
+pub mod slice;
+#[cfg(feature = "str")]
+pub mod str;
+#[cfg(feature = "sync")]
+pub mod sync;
+#[cfg(feature = "std")]
+pub mod thread;
+pub mod time;
+
+#[cfg(feature = "option")]
+pub use option::UnsafeOption;
+#[cfg(feature = "result")]
+pub use result::UnsafeResult;
+
+use core::ops::Bound;
+#[cfg(feature = "alloc")]
+use alloc::borrow::{Cow, ToOwned};
 
 /// A type whose instances can be unsafely unwrapped without checking.
 ///
@@ -33,38 +221,738 @@ pub trait UnsafeUnwrap<T> {
     ///
     /// This method trades safety for performance. Only use it when a wrapped
     /// value is known to exist. Otherwise, use `unwrap()` or pattern matching.
+    #[cfg_attr(feature = "strict", deprecated(
+        note = "the `strict` feature requires `unsafe_unwrap_because`",
+    ))]
     unsafe fn unsafe_unwrap(self) -> T;
+
+    /// Unsafely moves the inner value out of `self` without checking, stating
+    /// the `reason` why it is known to exist.
+    ///
+    /// The reason documents the invariant at the call site and is included in
+    /// the panic message of debug builds, where supported. With the `strict`
+    /// feature enabled, this is the only way to unwrap without a deprecation
+    /// warning, which can be made an error with `#![deny(deprecated)]`.
+    ///
+    /// # Safety
+    ///
+    /// A wrapped value must exist, as for `unsafe_unwrap()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::UnsafeUnwrap;
+    ///
+    /// let ids = [3, 1, 2];
+    /// let max = unsafe {
+    ///     ids.iter().max().unsafe_unwrap_because("`ids` is non-empty")
+    /// };
+    /// ```
+    #[inline]
+    #[cfg_attr(feature = "failure-hook", track_caller)]
+    unsafe fn unsafe_unwrap_because(self, reason: &'static str) -> T
+        where Self: Sized
+    {
+        let _ = reason;
+        self.unsafe_unwrap()
+    }
+
+    /// An alias of `unsafe_unwrap()` that matches the naming of `std`.
+    ///
+    /// `Option` and `Result` have inherent methods of the same name, which
+    /// take precedence with method syntax. Those are also checked in debug
+    /// builds, but abort rather than go through this crate's failure policy.
+    /// This alias applies to the other implementors and to generic code.
+    ///
+    /// # Safety
+    ///
+    /// A wrapped value must exist, as for `unsafe_unwrap()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::UnsafeUnwrap;
+    ///
+    /// unsafe fn get<T, U: UnsafeUnwrap<T>>(x: U) -> T {
+    ///     x.unwrap_unchecked()
+    /// }
+    /// assert_eq!(unsafe { get(Some(1)) }, 1);
+    /// ```
+    #[inline]
+    #[cfg_attr(feature = "failure-hook", track_caller)]
+    #[cfg_attr(feature = "strict", deprecated(
+        note = "the `strict` feature requires `expect_unchecked`",
+    ))]
+    unsafe fn unwrap_unchecked(self) -> T
+        where Self: Sized
+    {
+        self.unsafe_unwrap()
+    }
+
+    /// An alias of `unsafe_unwrap_because()` that matches the naming of
+    /// `expect()`.
+    ///
+    /// # Safety
+    ///
+    /// A wrapped value must exist, as for `unsafe_unwrap()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::UnsafeUnwrap;
+    ///
+    /// let ids = [3, 1, 2];
+    /// let max = unsafe {
+    ///     ids.iter().max().expect_unchecked("`ids` is non-empty")
+    /// };
+    /// ```
+    #[inline]
+    #[cfg_attr(feature = "failure-hook", track_caller)]
+    unsafe fn expect_unchecked(self, reason: &'static str) -> T
+        where Self: Sized
+    {
+        self.unsafe_unwrap_because(reason)
+    }
 }
 
-#[inline(always)]
+/// Whether invariants are checked rather than assumed.
+///
+/// Checks that are too expensive to leave for the optimizer to remove should
+/// only be evaluated when this is `true`.
+///
+/// Under Kani or the `verify` feature, invariants are always checked so that
+/// a violation fails the proof rather than being assumed away.
+const CHECKED: bool = cfg!(any(debug_assertions, kani, feature = "verify"));
+
+/// Whether failures trap instead of panicking in debug builds.
+const WASM_TRAP: bool = cfg!(all(target_arch = "wasm32", feature = "wasm-trap"));
+
+/// Whether failures panic with their message in debug builds, rather than
+/// with a static message, through the hook, or by trapping.
+const PANIC_MESSAGES: bool = CHECKED && !WASM_TRAP
+    && !cfg!(feature = "panic-lite") && !cfg!(feature = "failure-hook");
+
+#[cfg(feature = "failure-hook")]
+extern "Rust" {
+    /// Defined by the user to handle failures in debug builds.
+    fn unsafe_unwrap_failed(file: &str, line: u32) -> !;
+}
+
+// With `inline-never`, the checked failure paths are outlined so that they are
+// emitted once rather than at every call site.
+#[cfg_attr(
+    not(all(
+        feature = "inline-never",
+        any(debug_assertions, kani, feature = "verify"),
+    )),
+    inline(always)
+)]
+#[cfg_attr(
+    all(
+        feature = "inline-never",
+        any(debug_assertions, kani, feature = "verify"),
+    ),
+    cold,
+    inline(never)
+)]
+#[cfg_attr(feature = "failure-hook", track_caller)]
 unsafe fn unreachable() -> ! {
-    if cfg!(debug_assertions) {
-        unreachable!()
+    if (CHECKED && !WASM_TRAP) || runtime_checks() {
+        #[cfg(feature = "failure-hook")]
+        {
+            let location = core::panic::Location::caller();
+            unsafe_unwrap_failed(location.file(), location.line())
+        }
+        #[cfg(not(feature = "failure-hook"))]
+        {
+            unreachable!()
+        }
+    } else {
+        // The native instruction traps, which keeps `.wasm` modules free of
+        // panic strings and unwinding tables.
+        #[cfg(target_arch = "wasm32")]
+        {
+            core::arch::wasm32::unreachable()
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            core::hint::unreachable_unchecked()
+        }
+    }
+}
+
+/// Whether `UNSAFE_UNWRAP_CHECKS=1` enables checks in optimized builds.
+///
+/// The variable is read on the first call only.
+#[cfg(feature = "runtime-policy")]
+#[cold]
+#[inline(never)]
+fn runtime_checks() -> bool {
+    use core::sync::atomic::{AtomicU8, Ordering};
+
+    const UNREAD: u8 = 0;
+    const ASSUME: u8 = 1;
+    const CHECK: u8 = 2;
+
+    static POLICY: AtomicU8 = AtomicU8::new(UNREAD);
+
+    match POLICY.load(Ordering::Relaxed) {
+        UNREAD => {
+            let checked = std::env::var_os("UNSAFE_UNWRAP_CHECKS")
+                .is_some_and(|value| value == "1");
+            let policy = if checked { CHECK } else { ASSUME };
+            POLICY.store(policy, Ordering::Relaxed);
+            checked
+        }
+        policy => policy == CHECK,
+    }
+}
+
+#[cfg(not(feature = "runtime-policy"))]
+#[inline(always)]
+fn runtime_checks() -> bool {
+    false
+}
+
+/// Like `unreachable()`, but panics with `reason` in debug builds.
+///
+/// With `panic-lite`, `reason` is dropped so that only a static message is
+/// passed to the panic handler, without pulling in `core::fmt`. With
+/// `failure-hook`, `reason` is dropped in favor of the caller's location.
+#[cfg_attr(
+    not(all(
+        feature = "inline-never",
+        any(debug_assertions, kani, feature = "verify"),
+    )),
+    inline(always)
+)]
+#[cfg_attr(
+    all(
+        feature = "inline-never",
+        any(debug_assertions, kani, feature = "verify"),
+    ),
+    cold,
+    inline(never)
+)]
+#[cfg_attr(feature = "failure-hook", track_caller)]
+unsafe fn unreachable_because(reason: &'static str) -> ! {
+    if PANIC_MESSAGES {
+        panic!("invariant violated: {}", reason)
     } else {
-        use core::mem::transmute;
-        struct ZeroSized;
-        enum Impossible {}
-        match transmute::<_, Impossible>(ZeroSized) {}
+        unreachable()
     }
 }
 
+/// Tells the optimizer that `cond` holds, panicking in debug builds if not.
+#[inline(always)]
+#[cfg_attr(feature = "failure-hook", track_caller)]
+unsafe fn assume(cond: bool) {
+    if !cond { unreachable() }
+}
+
 impl<T> UnsafeUnwrap<T> for Option<T> {
-    #[inline]
-    unsafe fn unsafe_unwrap(self) -> T {
-        if let Some(x) = self { x } else { unreachable() }
+    inline_policy! {
+        #[cfg_attr(feature = "failure-hook", track_caller)]
+        unsafe fn unsafe_unwrap(self) -> T {
+            if let Some(x) = self { x } else { unreachable() }
+        }
+
+        #[cfg_attr(feature = "failure-hook", track_caller)]
+        unsafe fn unsafe_unwrap_because(self, reason: &'static str) -> T {
+            if let Some(x) = self { x } else { unreachable_because(reason) }
+        }
     }
 }
 
 impl<T, E> UnsafeUnwrap<T> for Result<T, E> {
+    inline_policy! {
+        #[cfg_attr(feature = "failure-hook", track_caller)]
+        unsafe fn unsafe_unwrap(self) -> T {
+            if let Ok(x) = self { x } else { unreachable() }
+        }
+
+        #[cfg_attr(feature = "failure-hook", track_caller)]
+        unsafe fn unsafe_unwrap_because(self, reason: &'static str) -> T {
+            if let Ok(x) = self { x } else { unreachable_because(reason) }
+        }
+    }
+}
+
+/// A `Try` type whose output can be unsafely unwrapped.
+///
+/// This covers `Option`, `Result`, `Poll`, `ControlFlow`, and third-party
+/// types alike. It is separate from `UnsafeUnwrap` so that enabling it leaves
+/// the impls of that trait, such as those for `Bound` and arrays, untouched.
+///
+/// This requires the nightly-only `try_trait` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::task::Poll;
+/// use unsafe_unwrap::UnsafeUnwrapTry;
+///
+/// let x: Poll<Result<u32, ()>> = Poll::Ready(Ok(1));
+/// assert_eq!(unsafe { x.unsafe_unwrap_try() }, Poll::Ready(1));
+/// ```
+#[cfg(feature = "try_trait")]
+pub trait UnsafeUnwrapTry: core::ops::Try {
+    /// Unsafely moves the output out of `self` without checking.
+    ///
+    /// # Safety
+    ///
+    /// `self` must not short-circuit: `self.branch()` must return `Continue`.
+    /// This is checked in debug builds.
+    unsafe fn unsafe_unwrap_try(self) -> Self::Output;
+
+    /// Unsafely moves the output out of `self` without checking, stating the
+    /// `reason` why it does not short-circuit.
+    ///
+    /// # Safety
+    ///
+    /// `self` must not short-circuit, as for `unsafe_unwrap_try()`.
+    unsafe fn unsafe_unwrap_try_because(
+        self,
+        reason: &'static str,
+    ) -> Self::Output;
+}
+
+#[cfg(feature = "try_trait")]
+impl<X: core::ops::Try> UnsafeUnwrapTry for X {
+    inline_policy! {
+        #[cfg_attr(feature = "failure-hook", track_caller)]
+        unsafe fn unsafe_unwrap_try(self) -> X::Output {
+            match self.branch() {
+                core::ops::ControlFlow::Continue(x) => x,
+                core::ops::ControlFlow::Break(_) => unreachable(),
+            }
+        }
+
+        #[cfg_attr(feature = "failure-hook", track_caller)]
+        unsafe fn unsafe_unwrap_try_because(
+            self,
+            reason: &'static str,
+        ) -> X::Output {
+            match self.branch() {
+                core::ops::ControlFlow::Continue(x) => x,
+                core::ops::ControlFlow::Break(_) => unreachable_because(reason),
+            }
+        }
+    }
+}
+
+/// A type that can be unsafely unwrapped by value, by reference, or by mutable
+/// reference.
+///
+/// Unlike `UnsafeUnwrap`, which only unwraps by value, this allows generic code
+/// to be written once over any ownership mode. The reference outputs are
+/// generic associated types, so implementors are not limited to returning
+/// plain references.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::UnsafeUnwrapExt;
+///
+/// // Works for `Option`, `Result`, and any other implementor.
+/// unsafe fn first<C: UnsafeUnwrapExt>(slots: &[C]) -> C::Ref<'_> {
+///     slots[0].unsafe_unwrap_ref()
+/// }
+///
+/// let slots = [Some(1), None];
+/// assert_eq!(*unsafe { first(&slots) }, 1);
+/// ```
+pub trait UnsafeUnwrapExt: Sized {
+    /// The value produced by unwrapping `self`.
+    type Owned;
+
+    /// The value produced by unwrapping `&'a self`.
+    type Ref<'a> where Self: 'a;
+
+    /// The value produced by unwrapping `&'a mut self`.
+    type Mut<'a> where Self: 'a;
+
+    /// Unsafely moves the inner value out of `self` without checking.
+    ///
+    /// # Safety
+    ///
+    /// A wrapped value must exist. This is checked in debug builds.
+    unsafe fn unsafe_unwrap_owned(self) -> Self::Owned;
+
+    /// Unsafely borrows the inner value of `self` without checking.
+    ///
+    /// # Safety
+    ///
+    /// A wrapped value must exist. This is checked in debug builds.
+    unsafe fn unsafe_unwrap_ref(&self) -> Self::Ref<'_>;
+
+    /// Unsafely mutably borrows the inner value of `self` without checking.
+    ///
+    /// # Safety
+    ///
+    /// A wrapped value must exist. This is checked in debug builds.
+    unsafe fn unsafe_unwrap_mut(&mut self) -> Self::Mut<'_>;
+}
+
+impl<T> UnsafeUnwrapExt for Option<T> {
+    type Owned = T;
+    type Ref<'a> = &'a T where T: 'a;
+    type Mut<'a> = &'a mut T where T: 'a;
+
+    inline_policy! {
+        unsafe fn unsafe_unwrap_owned(self) -> T {
+            if let Some(x) = self { x } else { unreachable() }
+        }
+
+        unsafe fn unsafe_unwrap_ref(&self) -> &T {
+            if let Some(ref x) = *self { x } else { unreachable() }
+        }
+
+        unsafe fn unsafe_unwrap_mut(&mut self) -> &mut T {
+            if let Some(ref mut x) = *self { x } else { unreachable() }
+        }
+    }
+}
+
+impl<T, E> UnsafeUnwrapExt for Result<T, E> {
+    type Owned = T;
+    type Ref<'a> = &'a T where T: 'a, E: 'a;
+    type Mut<'a> = &'a mut T where T: 'a, E: 'a;
+
+    inline_policy! {
+        unsafe fn unsafe_unwrap_owned(self) -> T {
+            if let Ok(x) = self { x } else { unreachable() }
+        }
+
+        unsafe fn unsafe_unwrap_ref(&self) -> &T {
+            if let Ok(ref x) = *self { x } else { unreachable() }
+        }
+
+        unsafe fn unsafe_unwrap_mut(&mut self) -> &mut T {
+            if let Ok(ref mut x) = *self { x } else { unreachable() }
+        }
+    }
+}
+
+impl<T> UnsafeUnwrapExt for Bound<T> {
+    type Owned = T;
+    type Ref<'a> = &'a T where T: 'a;
+    type Mut<'a> = &'a mut T where T: 'a;
+
+    inline_policy! {
+        unsafe fn unsafe_unwrap_owned(self) -> T {
+            match self {
+                Bound::Included(x) | Bound::Excluded(x) => x,
+                Bound::Unbounded => unreachable(),
+            }
+        }
+
+        unsafe fn unsafe_unwrap_ref(&self) -> &T {
+            match *self {
+                Bound::Included(ref x) | Bound::Excluded(ref x) => x,
+                Bound::Unbounded => unreachable(),
+            }
+        }
+
+        unsafe fn unsafe_unwrap_mut(&mut self) -> &mut T {
+            match *self {
+                Bound::Included(ref mut x) | Bound::Excluded(ref mut x) => x,
+                Bound::Unbounded => unreachable(),
+            }
+        }
+    }
+}
+
+/// A nested type whose instances can be unsafely unwrapped through both
+/// layers at once.
+///
+/// This performs a single combined check in debug builds and emits a single
+/// assumption in optimized builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::UnsafeUnwrapDeep;
+///
+/// let row: Result<Option<u32>, ()> = Ok(Some(7));
+/// assert_eq!(unsafe { row.unsafe_unwrap_deep() }, 7);
+/// ```
+pub trait UnsafeUnwrapDeep<T> {
+    /// Unsafely moves the innermost value out of `self` without checking.
+    ///
+    /// # Safety
+    ///
+    /// Both layers must contain a value: `Ok(Some(_))` or `Some(Ok(_))`. This
+    /// is checked in debug builds.
+    unsafe fn unsafe_unwrap_deep(self) -> T;
+}
+
+impl<T, E> UnsafeUnwrapDeep<T> for Result<Option<T>, E> {
+    inline_policy! {
+        unsafe fn unsafe_unwrap_deep(self) -> T {
+            if let Ok(Some(x)) = self { x } else { unreachable() }
+        }
+    }
+}
+
+impl<T, E> UnsafeUnwrapDeep<T> for Option<Result<T, E>> {
+    inline_policy! {
+        unsafe fn unsafe_unwrap_deep(self) -> T {
+            if let Some(Ok(x)) = self { x } else { unreachable() }
+        }
+    }
+}
+
+/// Unwraps every element of an array of options, assuming all are `Some`.
+///
+/// The values are moved into a new array without an intermediate allocation.
+impl<T, const N: usize> UnsafeUnwrap<[T; N]> for [Option<T>; N] {
+    inline_policy! {
+        unsafe fn unsafe_unwrap(self) -> [T; N] {
+            self.map(|x| x.unsafe_unwrap())
+        }
+    }
+}
+
+/// Unwraps every element of an array of results, assuming all are `Ok`.
+///
+/// The values are moved into a new array without an intermediate allocation.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::UnsafeUnwrap;
+///
+/// let replies: [Result<u32, ()>; 3] = [Ok(1), Ok(2), Ok(3)];
+/// assert_eq!(unsafe { replies.unsafe_unwrap() }, [1, 2, 3]);
+/// ```
+impl<T, E, const N: usize> UnsafeUnwrap<[T; N]> for [Result<T, E>; N] {
+    inline_policy! {
+        unsafe fn unsafe_unwrap(self) -> [T; N] {
+            self.map(|x| x.unsafe_unwrap())
+        }
+    }
+}
+
+/// Unwraps the value of an `Included` or `Excluded` bound, assuming it is not
+/// `Unbounded`.
+impl<T> UnsafeUnwrap<T> for Bound<T> {
+    inline_policy! {
+        #[cfg_attr(feature = "failure-hook", track_caller)]
+        unsafe fn unsafe_unwrap(self) -> T {
+            match self {
+                Bound::Included(x) | Bound::Excluded(x) => x,
+                Bound::Unbounded => unreachable(),
+            }
+        }
+    }
+}
+
+/// Per-variant unwrapping of `Bound`.
+///
+/// Use `Bound::as_ref` to unwrap by reference.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::ops::Bound;
+/// use unsafe_unwrap::UnsafeBound;
+///
+/// let start = Bound::Included(10);
+/// assert_eq!(unsafe { start.unwrap_included_unchecked() }, 10);
+/// ```
+pub trait UnsafeBound<T> {
+    /// Moves the value out of an `Included` bound without checking.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Included`. This is checked in debug builds.
+    unsafe fn unwrap_included_unchecked(self) -> T;
+
+    /// Moves the value out of an `Excluded` bound without checking.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Excluded`. This is checked in debug builds.
+    unsafe fn unwrap_excluded_unchecked(self) -> T;
+}
+
+impl<T> UnsafeBound<T> for Bound<T> {
+    #[inline]
+    unsafe fn unwrap_included_unchecked(self) -> T {
+        if let Bound::Included(x) = self { x } else { unreachable() }
+    }
+
+    #[inline]
+    unsafe fn unwrap_excluded_unchecked(self) -> T {
+        if let Bound::Excluded(x) = self { x } else { unreachable() }
+    }
+}
+
+/// Per-variant unwrapping of `Cow`.
+///
+/// This requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use std::borrow::Cow;
+/// use unsafe_unwrap::UnsafeCow;
+///
+/// let name: Cow<str> = Cow::Owned("normalized".to_owned());
+/// let name: String = unsafe { name.unwrap_owned_unchecked() };
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub trait UnsafeCow<'a, B: ?Sized + ToOwned> {
+    /// Moves the owned value out of an `Owned` cow without checking.
+    ///
+    /// Unlike `into_owned`, this never clones.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Owned`. This is checked in debug builds.
+    unsafe fn unwrap_owned_unchecked(self) -> B::Owned;
+
+    /// Returns the reference of a `Borrowed` cow without checking.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Borrowed`. This is checked in debug builds.
+    unsafe fn unwrap_borrowed_unchecked(self) -> &'a B;
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, B: ?Sized + ToOwned> UnsafeCow<'a, B> for Cow<'a, B> {
     #[inline]
-    unsafe fn unsafe_unwrap(self) -> T {
-        if let Ok(x) = self { x } else { unreachable() }
+    unsafe fn unwrap_owned_unchecked(self) -> B::Owned {
+        if let Cow::Owned(x) = self { x } else { unreachable() }
+    }
+
+    #[inline]
+    unsafe fn unwrap_borrowed_unchecked(self) -> &'a B {
+        if let Cow::Borrowed(x) = self { x } else { unreachable() }
+    }
+}
+
+/// A fieldless enum that can be unsafely created from its discriminant.
+///
+/// This can be derived with `#[derive(UnsafeFromPrimitive)]` when the `macros`
+/// feature is enabled. The enum must have an integer `#[repr]`, which becomes
+/// its `Primitive` type.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// use unsafe_unwrap::UnsafeFromPrimitive;
+///
+/// #[derive(UnsafeFromPrimitive, PartialEq, Debug)]
+/// #[repr(u8)]
+/// enum Opcode {
+///     Nop = 0x00,
+///     Push = 0x10,
+///     Pop,
+/// }
+///
+/// let op = unsafe { Opcode::from_discriminant_unchecked(0x11) };
+/// assert_eq!(op, Opcode::Pop);
+/// # }
+/// ```
+pub trait UnsafeFromPrimitive: Sized {
+    /// The integer type of the enum's discriminant.
+    type Primitive: Copy;
+
+    /// Converts `value` into the variant with that discriminant without
+    /// checking that one exists.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be the discriminant of one of the enum's variants. This is
+    /// checked in debug builds.
+    unsafe fn from_discriminant_unchecked(value: Self::Primitive) -> Self;
+}
+
+// Not public API. Used by the code generated in `unsafe_unwrap_macros` and by
+// the `macro_rules!` macros of this crate.
+#[doc(hidden)]
+pub mod __private {
+    pub use core::mem::transmute;
+
+    use core::{mem, ptr};
+
+    pub const PANIC_MESSAGES: bool = ::PANIC_MESSAGES;
+
+    #[inline(always)]
+    pub unsafe fn assume(cond: bool) {
+        ::assume(cond)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "failure-hook", track_caller)]
+    pub unsafe fn unreachable() -> ! {
+        ::unreachable()
+    }
+
+    // `Result::unwrap` is not a `const fn`, so each supported type gets its
+    // own inherent `unwrap` on this wrapper. The value is read out and the
+    // wrapper forgotten, since destructors cannot run in a `const fn`.
+    pub struct StaticUnwrap<T>(pub T);
+
+    impl<T> StaticUnwrap<Option<T>> {
+        pub const fn unwrap(self) -> T {
+            let value = match &self.0 {
+                Some(x) => unsafe { ptr::read(x) },
+                None => panic!("`static_unwrap!` on a `None` value"),
+            };
+            mem::forget(self);
+            value
+        }
+    }
+
+    impl<T, E> StaticUnwrap<Result<T, E>> {
+        pub const fn unwrap(self) -> T {
+            let value = match &self.0 {
+                Ok(x) => unsafe { ptr::read(x) },
+                Err(_) => panic!("`static_unwrap!` on an `Err` value"),
+            };
+            mem::forget(self);
+            value
+        }
+    }
+}
+
+#[cfg(kani)]
+mod proofs {
+    use super::*;
+
+    #[kani::proof]
+    fn option_some_unwraps() {
+        let x: Option<u8> = kani::any();
+        kani::assume(x.is_some());
+        unsafe { x.unsafe_unwrap_because("assumed `Some`") };
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn option_none_fails() {
+        let x: Option<u8> = kani::any();
+        unsafe { x.unsafe_unwrap_because("unconstrained") };
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn result_err_fails() {
+        let x: Result<u8, u8> = kani::any();
+        unsafe { x.unsafe_unwrap_because("unconstrained") };
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use core::ops::DerefMut;
 
     #[test]
     fn option_unwrap_success() {
@@ -99,33 +987,272 @@ mod tests {
             x.unsafe_unwrap();
         }
     }
-}
 
-#[cfg(test)]
-mod benches {
-    extern crate test;
-    use self::test::{Bencher, black_box};
-    use super::*;
+    #[test]
+    fn unwrap_because_success() {
+        unsafe {
+            assert_eq!(Some(0).unsafe_unwrap_because("is some"), 0);
+            let x: Result<_, ()> = Ok(1);
+            assert_eq!(x.unsafe_unwrap_because("is ok"), 1);
+        }
+    }
 
-    static OPT: Option<u32> = Some(0);
+    #[test]
+    #[cfg_attr(not(feature = "panic-lite"),
+               should_panic(expected = "invariant violated: is ok"))]
+    #[cfg_attr(feature = "panic-lite", should_panic)]
+    fn unwrap_because_failure() {
+        unsafe {
+            let x: Result<(), _> = Err(0);
+            x.unsafe_unwrap_because("is ok");
+        }
+    }
 
-    #[bench]
-    fn bench_normal_unwrap_1000(b: &mut Bencher) {
-        let r = OPT.as_ref();
-        b.iter(|| {
-            for _ in 0..1000 {
-                black_box(black_box(r).unwrap());
-            }
-        });
+    #[test]
+    fn std_aligned_success() {
+        unsafe {
+            assert_eq!(UnsafeUnwrap::unwrap_unchecked(Some(1)), 1);
+            assert_eq!(Ok::<u8, ()>(2).expect_unchecked("`Ok`"), 2);
+        }
     }
 
-    #[bench]
-    fn bench_unsafe_unwrap_1000(b: &mut Bencher) {
-        let r = OPT.as_ref();
-        b.iter(|| unsafe {
-            for _ in 0..1000 {
-                black_box(black_box(r).unsafe_unwrap());
-            }
-        });
+    #[test]
+    #[should_panic]
+    fn std_aligned_failure() {
+        unsafe { UnsafeUnwrap::unwrap_unchecked(None::<u8>) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn std_aligned_expect_failure() {
+        unsafe { None::<u8>.expect_unchecked("never") };
+    }
+
+    unsafe fn bump<C>(c: &mut C)
+        where C: UnsafeUnwrapExt,
+              for<'a> C::Mut<'a>: DerefMut<Target = u8>
+    {
+        *c.unsafe_unwrap_mut() += 1;
+    }
+
+    #[test]
+    fn ext_unwrap_success() {
+        unsafe {
+            let mut x = Some(0u8);
+            bump(&mut x);
+            assert_eq!(*x.unsafe_unwrap_ref(), 1);
+            let mut y: Result<u8, ()> = Ok(1);
+            bump(&mut y);
+            assert_eq!(y.unsafe_unwrap_owned(), 2);
+            let mut z = Bound::Excluded(2u8);
+            bump(&mut z);
+            assert_eq!(z.unsafe_unwrap_owned(), 3);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn ext_unwrap_failure() {
+        unsafe {
+            let x: Result<(), ()> = Err(());
+            x.unsafe_unwrap_ref();
+        }
+    }
+
+    #[test]
+    fn deep_unwrap_success() {
+        unsafe {
+            let x: Result<_, ()> = Ok(Some(0));
+            assert_eq!(x.unsafe_unwrap_deep(), 0);
+            let x: Option<Result<_, ()>> = Some(Ok(1));
+            assert_eq!(x.unsafe_unwrap_deep(), 1);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn deep_unwrap_failure() {
+        unsafe {
+            let x: Result<Option<()>, ()> = Ok(None);
+            x.unsafe_unwrap_deep();
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "try_trait")]
+    fn try_unwrap_success() {
+        use core::ops::ControlFlow;
+        use core::task::Poll;
+        unsafe {
+            let x: Poll<Result<_, ()>> = Poll::Ready(Ok(0));
+            assert_eq!(x.unsafe_unwrap_try(), Poll::Ready(0));
+            let x: ControlFlow<(), _> = ControlFlow::Continue(1);
+            assert_eq!(x.unsafe_unwrap_try(), 1);
+            assert_eq!(Some(2).unsafe_unwrap_try_because("`Some`"), 2);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "try_trait")]
+    fn try_unwrap_failure() {
+        use core::ops::ControlFlow;
+        unsafe {
+            let x: ControlFlow<(), ()> = ControlFlow::Break(());
+            x.unsafe_unwrap_try();
+        }
+    }
+
+    #[test]
+        fn array_unwrap_success() {
+        unsafe {
+            assert_eq!([Some(1), Some(2)].unsafe_unwrap(), [1, 2]);
+            let x: [Result<_, ()>; 2] = [Ok(1), Ok(2)];
+            assert_eq!(x.unsafe_unwrap(), [1, 2]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+        fn array_unwrap_failure() {
+        unsafe { [Ok(1), Err(())].unsafe_unwrap() };
+    }
+
+    #[test]
+    fn bound_unwrap_success() {
+        unsafe {
+                        assert_eq!(Bound::Included(1).unsafe_unwrap(), 1);
+                        assert_eq!(Bound::Excluded(2).unsafe_unwrap(), 2);
+            assert_eq!(Bound::Included(3).unwrap_included_unchecked(), 3);
+            assert_eq!(Bound::Excluded(4).unwrap_excluded_unchecked(), 4);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+        fn bound_unwrap_failure() {
+        unsafe {
+            let x: Bound<()> = Bound::Unbounded;
+            x.unsafe_unwrap();
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn bound_unwrap_included_failure() {
+        unsafe { Bound::Excluded(0).unwrap_included_unchecked() };
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn cow_unwrap_success() {
+        unsafe {
+            let owned: Cow<[u8]> = Cow::Owned(alloc::vec![1]);
+            assert_eq!(owned.unwrap_owned_unchecked(), [1]);
+            let borrowed: Cow<[u8]> = Cow::Borrowed(&[2]);
+            assert_eq!(borrowed.unwrap_borrowed_unchecked(), [2]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "alloc")]
+    fn cow_unwrap_failure() {
+        unsafe {
+            let borrowed: Cow<str> = Cow::Borrowed("");
+            borrowed.unwrap_owned_unchecked();
+        }
+    }
+
+    #[cfg(feature = "macros")]
+    #[derive(UnsafeFromPrimitive, PartialEq, Debug)]
+    #[repr(i16)]
+    enum Discriminants {
+        A = -3,
+        B,
+        C = 40,
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn from_discriminant_success() {
+        use self::Discriminants::*;
+        unsafe {
+            assert_eq!(Discriminants::from_discriminant_unchecked(-3), A);
+            assert_eq!(Discriminants::from_discriminant_unchecked(-2), B);
+            assert_eq!(Discriminants::from_discriminant_unchecked(40), C);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "macros")]
+    fn from_discriminant_failure() {
+        unsafe {
+            Discriminants::from_discriminant_unchecked(0);
+        }
+    }
+
+    #[cfg(feature = "macros")]
+    #[requires(i < v.len())]
+    #[ensures(ret != 0)]
+    unsafe fn nonzero_at(v: &[u8], i: usize) -> u8 {
+        if i == 0 {
+            return v[0];
+        }
+        v[i]
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn contract_success() {
+        unsafe {
+            assert_eq!(nonzero_at(&[1, 2], 0), 1);
+            assert_eq!(nonzero_at(&[1, 2], 1), 2);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "macros")]
+    fn requires_failure() {
+        unsafe {
+            nonzero_at(&[1, 2], 2);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "macros")]
+    fn ensures_failure() {
+        unsafe {
+            nonzero_at(&[0, 2], 0);
+        }
+    }
+
+    #[cfg(feature = "macros")]
+    #[assume_unwraps(question_mark)]
+    unsafe fn sum_firsts(rows: &[&[u32]]) -> Option<u32> {
+        let first = rows.first().unwrap();
+        let last = rows.last().expect("`rows` is non-empty");
+        let doubled = last.iter().map(|x| x.checked_mul(2).unwrap());
+        Some(*first.first()? + doubled.sum::<u32>())
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn assume_unwraps_success() {
+        unsafe {
+            assert_eq!(sum_firsts(&[&[1, 2], &[3, 4]]), Some(15));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "macros")]
+    fn assume_unwraps_failure() {
+        unsafe {
+            sum_firsts(&[&[], &[3]]);
+        }
     }
 }
@@ -0,0 +1,139 @@
+//! The `macro_rules!` macros of this crate.
+//!
+//! Exported macros are available from the crate root, as are the procedural
+//! macros of the `macros` feature.
+
+#[cfg(all(feature = "inline-always", feature = "inline-never"))]
+compile_error!("`inline-always` and `inline-never` are mutually exclusive");
+
+/// Applies the inlining policy of the `inline-always` and `inline-never`
+/// features to the functions within, in place of `#[inline]`.
+macro_rules! inline_policy {
+    ($($item:item)+) => {
+        $(
+            #[cfg_attr(feature = "inline-always", inline(always))]
+            #[cfg_attr(feature = "inline-never", inline(never))]
+            #[cfg_attr(
+                not(any(feature = "inline-always", feature = "inline-never")),
+                inline
+            )]
+            $item
+        )+
+    };
+}
+
+/// Marks a code path as unreachable, with the same debug and release behavior
+/// as a failed unwrap.
+///
+/// In debug builds, this panics like `unreachable!()`, taking the same
+/// optional message. In optimized builds, it is
+/// `core::hint::unreachable_unchecked()`. The `failure-hook`, `panic-lite`,
+/// `verify`, and `wasm-trap` features apply to it as to any other failure.
+///
+/// # Safety
+///
+/// This must never be reached. Reaching it is checked in debug builds.
+///
+/// # Examples
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate unsafe_unwrap;
+///
+/// fn class(byte: u8) -> &'static str {
+///     match byte & 0b11 {
+///         0 => "nop",
+///         1 => "load",
+///         2 => "store",
+///         3 => "jump",
+///         other => unsafe { debug_unreachable!("2-bit value {}", other) },
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(class(0xfe), "store");
+/// }
+/// ```
+#[macro_export]
+macro_rules! debug_unreachable {
+    ($($arg:tt)*) => {
+        if $crate::__private::PANIC_MESSAGES {
+            unreachable!($($arg)*)
+        } else {
+            $crate::__private::unreachable()
+        }
+    };
+}
+
+/// Unwraps a constant `Option` or `Result` at compile time.
+///
+/// The expression is evaluated in a `const` block, so an absent value is a
+/// compile error and a present one leaves no runtime code. Unlike the rest of
+/// this crate, this is entirely safe.
+///
+/// # Examples
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate unsafe_unwrap;
+///
+/// use std::num::NonZeroU8;
+///
+/// const ALIGN: NonZeroU8 = static_unwrap!(NonZeroU8::new(8));
+/// const LIMIT: u32 = static_unwrap!(1024u32.checked_mul(ALIGN.get() as u32));
+///
+/// fn main() {
+///     assert_eq!(LIMIT, 8192);
+/// }
+/// ```
+///
+/// An absent value fails to compile:
+///
+/// ```compile_fail
+/// #[macro_use]
+/// extern crate unsafe_unwrap;
+///
+/// const OVERFLOW: u8 = static_unwrap!(255u8.checked_add(1));
+///
+/// fn main() {}
+/// ```
+#[macro_export]
+macro_rules! static_unwrap {
+    ($e:expr) => {
+        const { $crate::__private::StaticUnwrap($e).unwrap() }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn debug_unreachable_success() {
+        let x = 3u8;
+        let parity = match x % 2 {
+            0 => "even",
+            1 => "odd",
+            _ => unsafe { debug_unreachable!() },
+        };
+        assert_eq!(parity, "odd");
+    }
+
+    #[test]
+    #[should_panic]
+    fn debug_unreachable_failure() {
+        unsafe { debug_unreachable!("reached with {}", 1) };
+    }
+
+    #[test]
+    fn static_unwrap_success() {
+        use core::num::NonZeroU8;
+
+        const ONE: NonZeroU8 = static_unwrap!(NonZeroU8::new(1));
+        const NAME: &str = static_unwrap!(core::str::from_utf8(b"name"));
+        #[cfg(feature = "alloc")]
+        const EMPTY: alloc::string::String =
+            static_unwrap!(Ok::<_, ()>(alloc::string::String::new()));
+        assert_eq!((ONE.get(), NAME), (1, "name"));
+        #[cfg(feature = "alloc")]
+        assert!(EMPTY.is_empty());
+    }
+}
@@ -0,0 +1,98 @@
+//! Unchecked parsing of network addresses.
+//!
+//! These are intended for endpoints that were validated once, such as when
+//! loading configuration, and are parsed again in hot paths.
+//! `first_socket_addr_unchecked` requires the `std` feature.
+
+use core::net::{IpAddr, SocketAddr};
+#[cfg(feature = "std")]
+use std::net::ToSocketAddrs;
+
+use UnsafeUnwrap;
+
+/// Parses an IP address from `s`, assuming that it is well-formed.
+///
+/// # Safety
+///
+/// `s.parse::<IpAddr>()` must be `Ok`. This is checked in debug builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::net::ip_addr_unchecked;
+///
+/// let ip = unsafe { ip_addr_unchecked("127.0.0.1") };
+/// assert!(ip.is_loopback());
+/// ```
+#[inline]
+pub unsafe fn ip_addr_unchecked(s: &str) -> IpAddr {
+    s.parse().unsafe_unwrap()
+}
+
+/// Parses a socket address from `s`, assuming that it is well-formed.
+///
+/// # Safety
+///
+/// `s.parse::<SocketAddr>()` must be `Ok`. This is checked in debug builds.
+#[inline]
+pub unsafe fn socket_addr_unchecked(s: &str) -> SocketAddr {
+    s.parse().unsafe_unwrap()
+}
+
+/// Resolves `addr` and returns its first socket address, assuming that
+/// resolution succeeds with at least one address.
+///
+/// # Safety
+///
+/// `addr.to_socket_addrs()` must be `Ok` and yield an address. This is
+/// checked in debug builds.
+#[cfg(feature = "std")]
+#[inline]
+pub unsafe fn first_socket_addr_unchecked<A>(addr: A) -> SocketAddr
+    where A: ToSocketAddrs
+{
+    addr.to_socket_addrs().unsafe_unwrap().next().unsafe_unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::net::Ipv6Addr;
+
+    #[test]
+    fn addr_success() {
+        unsafe {
+            let ip = ip_addr_unchecked("::1");
+            assert_eq!(ip, IpAddr::V6(Ipv6Addr::LOCALHOST));
+            let addr = socket_addr_unchecked("[::1]:8080");
+            assert_eq!((addr.ip(), addr.port()), (ip, 8080));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn ip_addr_failure() {
+        unsafe { ip_addr_unchecked("256.0.0.1") };
+    }
+
+    #[test]
+    #[should_panic]
+    fn socket_addr_failure() {
+        unsafe { socket_addr_unchecked("127.0.0.1") };
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn first_socket_addr_success() {
+        let addr = unsafe { first_socket_addr_unchecked(("127.0.0.1", 80)) };
+        assert_eq!(addr, unsafe { socket_addr_unchecked("127.0.0.1:80") });
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "std")]
+    fn first_socket_addr_failure() {
+        let none: &[SocketAddr] = &[];
+        unsafe { first_socket_addr_unchecked(none) };
+    }
+}
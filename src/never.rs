@@ -0,0 +1,136 @@
+//! A stable uninhabited type for infallible results.
+//!
+//! [`Never`] stands in for the unstable `!` type, so that APIs can return
+//! `Result<T, Never>` and callers can extract the value with the safe
+//! [`IntoOk::into_ok`] instead of `unsafe_unwrap()`.
+//!
+//! [`Never`]: enum.Never.html
+//! [`IntoOk::into_ok`]: trait.IntoOk.html#tymethod.into_ok
+
+use core::convert::Infallible;
+use core::error::Error;
+use core::fmt;
+
+/// A type with no values.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::never::{IntoOk, Never};
+///
+/// fn parse_lossy(s: &str) -> Result<u32, Never> {
+///     Ok(s.parse().unwrap_or(0))
+/// }
+///
+/// assert_eq!(IntoOk::into_ok(parse_lossy("12")), 12);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Never {}
+
+impl Never {
+    /// Converts `self` into any type, since it can never be called.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::never::Never;
+    ///
+    /// let res: Result<u8, Never> = Ok(1);
+    /// let res: Result<u8, String> = res.map_err(Never::absurd);
+    /// assert_eq!(res, Ok(1));
+    /// ```
+    #[inline]
+    pub fn absurd<T>(self) -> T {
+        match self {}
+    }
+}
+
+/// Converts `never` into any type.
+///
+/// This is shorthand for [`Never::absurd`], for use in place of the
+/// `From<Never>` impl that cannot be written for every type.
+///
+/// [`Never::absurd`]: enum.Never.html#method.absurd
+#[inline]
+pub fn absorb<T>(never: Never) -> T {
+    match never {}
+}
+
+impl From<Infallible> for Never {
+    #[inline]
+    fn from(x: Infallible) -> Never {
+        match x {}
+    }
+}
+
+impl From<Never> for Infallible {
+    #[inline]
+    fn from(x: Never) -> Infallible {
+        match x {}
+    }
+}
+
+impl fmt::Display for Never {
+    fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+        match *self {}
+    }
+}
+
+impl Error for Never {}
+
+/// Safe unwrapping of a `Result` whose error type is uninhabited.
+///
+/// While the inherent `Result::into_ok` is unstable, calling this with method
+/// syntax triggers the `unstable_name_collisions` lint, so call it as
+/// `IntoOk::into_ok(res)` instead.
+pub trait IntoOk<T> {
+    /// Returns the `Ok` value, which is always present.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::convert::Infallible;
+    /// use unsafe_unwrap::never::IntoOk;
+    ///
+    /// let res: Result<u8, Infallible> = Ok(1);
+    /// assert_eq!(IntoOk::into_ok(res), 1);
+    /// ```
+    fn into_ok(self) -> T;
+}
+
+impl<T> IntoOk<T> for Result<T, Never> {
+    #[inline]
+    fn into_ok(self) -> T {
+        match self {
+            Ok(x) => x,
+            Err(e) => e.absurd(),
+        }
+    }
+}
+
+impl<T> IntoOk<T> for Result<T, Infallible> {
+    #[inline]
+    fn into_ok(self) -> T {
+        match self {
+            Ok(x) => x,
+            Err(e) => match e {},
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_ok() {
+        assert_eq!(IntoOk::into_ok(Ok::<u8, Never>(1)), 1);
+        assert_eq!(IntoOk::into_ok(Ok::<u8, Infallible>(1)), 1);
+    }
+
+    #[test]
+    fn absorb_errors() {
+        let res = Ok::<u8, Never>(1).map_err(absorb::<Infallible>);
+        assert_eq!(IntoOk::into_ok(res.map_err(Never::from)), 1);
+    }
+}
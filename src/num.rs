@@ -0,0 +1,138 @@
+//! Unchecked integer logarithms and powers of two.
+//!
+//! These are intended for size-class arithmetic and similar hot paths where
+//! the zero and overflow cases were ruled out by an earlier check.
+
+use UnsafeUnwrap;
+
+/// Integer logarithms that assume a valid input.
+pub trait UnsafeInt: Sized {
+    /// Returns the base `base` logarithm of `self`, rounded down, assuming
+    /// that it is defined.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be positive and `base` must be at least 2. This is checked
+    /// in debug builds.
+    unsafe fn ilog_unchecked(self, base: Self) -> u32;
+
+    /// Returns the base 2 logarithm of `self`, rounded down, assuming that it
+    /// is defined.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be positive. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::num::UnsafeInt;
+    ///
+    /// fn size_class(size: usize) -> u32 {
+    ///     if size <= 16 {
+    ///         return 0;
+    ///     }
+    ///     unsafe { (size - 1).ilog2_unchecked() - 3 }
+    /// }
+    /// assert_eq!(size_class(17), 1);
+    /// assert_eq!(size_class(33), 2);
+    /// ```
+    unsafe fn ilog2_unchecked(self) -> u32;
+}
+
+/// Powers of two that assume no overflow.
+pub trait UnsafeUint: UnsafeInt {
+    /// Returns the smallest power of two greater than or equal to `self`,
+    /// assuming that it is representable.
+    ///
+    /// # Safety
+    ///
+    /// The result must not overflow `Self`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::num::UnsafeUint;
+    ///
+    /// // Requests are capped well below `u32::MAX`.
+    /// let request: u32 = 3000;
+    /// assert_eq!(unsafe { request.next_power_of_two_unchecked() }, 4096);
+    /// ```
+    unsafe fn next_power_of_two_unchecked(self) -> Self;
+}
+
+macro_rules! impl_unsafe_int {
+    ($($int:ty)+) => {
+        $(
+            impl UnsafeInt for $int {
+                #[inline]
+                unsafe fn ilog_unchecked(self, base: $int) -> u32 {
+                    self.checked_ilog(base).unsafe_unwrap()
+                }
+
+                #[inline]
+                unsafe fn ilog2_unchecked(self) -> u32 {
+                    self.checked_ilog2().unsafe_unwrap()
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_unsafe_uint {
+    ($($uint:ty)+) => {
+        $(
+            impl UnsafeUint for $uint {
+                #[inline]
+                unsafe fn next_power_of_two_unchecked(self) -> $uint {
+                    self.checked_next_power_of_two().unsafe_unwrap()
+                }
+            }
+        )+
+    };
+}
+
+impl_unsafe_int!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+impl_unsafe_uint!(u8 u16 u32 u64 u128 usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ilog_success() {
+        unsafe {
+            assert_eq!(1000u32.ilog_unchecked(10), 3);
+            assert_eq!(9i64.ilog_unchecked(3), 2);
+            assert_eq!(1u8.ilog2_unchecked(), 0);
+            assert_eq!(i16::MAX.ilog2_unchecked(), 14);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn ilog_failure() {
+        unsafe { 8u32.ilog_unchecked(1) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn ilog2_failure() {
+        unsafe { (-1i32).ilog2_unchecked() };
+    }
+
+    #[test]
+    fn next_power_of_two_success() {
+        unsafe {
+            assert_eq!(0u8.next_power_of_two_unchecked(), 1);
+            assert_eq!(128u8.next_power_of_two_unchecked(), 128);
+            assert_eq!(129u16.next_power_of_two_unchecked(), 256);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn next_power_of_two_failure() {
+        unsafe { 129u8.next_power_of_two_unchecked() };
+    }
+}
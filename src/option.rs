@@ -0,0 +1,238 @@
+//! Unchecked operations specific to `Option`.
+//!
+//! This module requires the `option` feature, which is enabled by default.
+//! Its trait is also re-exported from the crate root.
+
+use core::hint;
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut};
+
+use {UnsafeUnwrap, unreachable};
+
+/// Unchecked operations specific to `Option`.
+pub trait UnsafeOption<T> {
+    /// Dereferences the inner value without checking that it exists.
+    ///
+    /// This combines `as_deref()` and `unsafe_unwrap()` without creating an
+    /// intermediate `Option<&T::Target>`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::UnsafeOption;
+    ///
+    /// let name = Some(String::from("row"));
+    /// let name: &str = unsafe { name.unsafe_as_deref() };
+    /// ```
+    unsafe fn unsafe_as_deref(&self) -> &T::Target where T: Deref;
+
+    /// Mutably dereferences the inner value without checking that it exists.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some`. This is checked in debug builds.
+    unsafe fn unsafe_as_deref_mut(&mut self) -> &mut T::Target
+        where T: DerefMut;
+
+    /// Maps the inner value with `f` without checking that it exists,
+    /// keeping the result wrapped in `Some`.
+    ///
+    /// Unlike unwrapping, this lets the assumption be carried through a chain
+    /// of adapters to a final safe consumer.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::UnsafeOption;
+    ///
+    /// fn last_index(word: Option<&str>) -> Option<usize> {
+    ///     let len = unsafe { word.map_unchecked(str::len) };
+    ///     len.and_then(|len| len.checked_sub(1))
+    /// }
+    /// # assert_eq!(last_index(Some("abc")), Some(2));
+    /// ```
+    unsafe fn map_unchecked<U, F>(self, f: F) -> Option<U>
+        where F: FnOnce(T) -> U;
+
+    /// Calls `f` with the inner value without checking that it exists,
+    /// returning its result.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some`. This is checked in debug builds.
+    unsafe fn and_then_unchecked<U, F>(self, f: F) -> Option<U>
+        where F: FnOnce(T) -> Option<U>;
+
+    /// Returns the inner value or `default`, selecting between them without
+    /// a branch.
+    ///
+    /// Unlike the rest of this trait, this is safe and handles `None`. It
+    /// uses `core::hint::select_unpredictable`, which encourages a
+    /// conditional move rather than a branch that is often mispredicted when
+    /// `self` is unpredictable. Prefer `unwrap_or` when it is predictable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::UnsafeOption;
+    ///
+    /// let table = [Some(4u8), None, Some(9)];
+    /// let sum: u32 = table.iter().map(|x| x.unwrap_or_select(0) as u32).sum();
+    /// assert_eq!(sum, 13);
+    /// ```
+    fn unwrap_or_select(self, default: T) -> T where T: Copy;
+
+    /// Moves the inner value into a `MaybeUninit<T>` without checking that it
+    /// exists.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::mem::{self, MaybeUninit};
+    /// use unsafe_unwrap::UnsafeOption;
+    ///
+    /// let item = Some(String::from("conn"));
+    /// // Releasing to the pool always passes an acquired item.
+    /// let mut slot = unsafe { item.into_maybe_uninit() };
+    /// // Acquiring takes the item back out of the occupied slot.
+    /// let item = mem::replace(&mut slot, MaybeUninit::uninit());
+    /// let item = unsafe { Option::from_maybe_uninit_some(item) };
+    /// assert_eq!(item.as_deref(), Some("conn"));
+    /// ```
+    unsafe fn into_maybe_uninit(self) -> MaybeUninit<T>;
+
+    /// Wraps the value of `value` in `Some`, assuming that it is initialized.
+    ///
+    /// This is the reverse of `into_maybe_uninit()`.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be initialized. Unlike the rest of this trait, this cannot
+    /// be checked in debug builds.
+    unsafe fn from_maybe_uninit_some(value: MaybeUninit<T>) -> Self
+        where Self: Sized;
+}
+
+impl<T> UnsafeOption<T> for Option<T> {
+    #[inline]
+    unsafe fn unsafe_as_deref(&self) -> &T::Target where T: Deref {
+        if let Some(ref x) = *self { x } else { unreachable() }
+    }
+
+    #[inline]
+    unsafe fn unsafe_as_deref_mut(&mut self) -> &mut T::Target
+        where T: DerefMut
+    {
+        if let Some(ref mut x) = *self { x } else { unreachable() }
+    }
+
+    #[inline]
+    unsafe fn map_unchecked<U, F>(self, f: F) -> Option<U>
+        where F: FnOnce(T) -> U
+    {
+        Some(f(self.unsafe_unwrap()))
+    }
+
+    #[inline]
+    unsafe fn and_then_unchecked<U, F>(self, f: F) -> Option<U>
+        where F: FnOnce(T) -> Option<U>
+    {
+        f(self.unsafe_unwrap())
+    }
+
+    #[inline]
+    fn unwrap_or_select(self, default: T) -> T where T: Copy {
+        let is_some = self.is_some();
+        // With an uninitialized `None` arm, this compiles to a plain read of
+        // the payload.
+        let value = match self {
+            Some(x) => MaybeUninit::new(x),
+            None => MaybeUninit::uninit(),
+        };
+        let value = hint::select_unpredictable(
+            is_some,
+            value,
+            MaybeUninit::new(default),
+        );
+        // `value` is only selected if it is initialized.
+        unsafe { value.assume_init() }
+    }
+
+    #[inline]
+    unsafe fn into_maybe_uninit(self) -> MaybeUninit<T> {
+        if let Some(x) = self { MaybeUninit::new(x) } else { unreachable() }
+    }
+
+    #[inline]
+    unsafe fn from_maybe_uninit_some(value: MaybeUninit<T>) -> Option<T> {
+        Some(value.assume_init())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn option_as_deref_success() {
+        unsafe {
+            let mut x = Some(&mut [1, 2][..]);
+            x.unsafe_as_deref_mut()[0] = 0;
+            assert_eq!(x.unsafe_as_deref(), [0, 2]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn option_as_deref_failure() {
+        unsafe {
+            let x: Option<&str> = None;
+            x.unsafe_as_deref();
+        }
+    }
+
+    #[test]
+    fn option_map_success() {
+        unsafe {
+            assert_eq!(Some(1).map_unchecked(|x| x + 1), Some(2));
+            assert_eq!(Some(1).and_then_unchecked(|_| None::<()>), None);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn option_map_failure() {
+        unsafe { None::<u8>.map_unchecked(|x| x) };
+    }
+
+    #[test]
+    fn option_maybe_uninit_success() {
+        let x = unsafe { Some(1u8).into_maybe_uninit() };
+        assert_eq!(unsafe { Option::from_maybe_uninit_some(x) }, Some(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn option_maybe_uninit_failure() {
+        unsafe { None::<u8>.into_maybe_uninit() };
+    }
+
+    #[test]
+    fn option_unwrap_or_select() {
+        assert_eq!(Some(1u8).unwrap_or_select(2), 1);
+        assert_eq!(None::<u8>.unwrap_or_select(2), 2);
+        assert_eq!(Some("x").unwrap_or_select("y"), "x");
+    }
+}
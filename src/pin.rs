@@ -0,0 +1,211 @@
+//! Unchecked pinning with debug-build checks.
+//!
+//! [`UnsafePin`] mirrors the `unsafe` methods of `Pin`, checking in debug
+//! builds what their contracts allow: that the pinned target does not move
+//! along with its pointer, and that projections stay within the pinned value.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::pin::Pin;
+//! use unsafe_unwrap::pin::UnsafePin;
+//!
+//! struct Future {
+//!     state: u32,
+//! }
+//!
+//! fn state(this: Pin<&mut Future>) -> Pin<&mut u32> {
+//!     // `state` is structurally pinned.
+//!     unsafe { this.project_unchecked_mut(|f| &mut f.state) }
+//! }
+//! ```
+//!
+//! [`UnsafePin`]: trait.UnsafePin.html
+
+use core::mem;
+use core::ops::{Deref, DerefMut};
+use core::pin::Pin;
+
+use {CHECKED, unreachable};
+
+/// Unchecked operations on `Pin`.
+///
+/// Each method is named apart from the inherent `Pin` method it mirrors, which
+/// would otherwise take precedence over it and skip its checks.
+pub trait UnsafePin<P: Deref>: Sized {
+    /// Pins `pointer` without checking that its target is never moved.
+    ///
+    /// This is equivalent to `Pin::new_unchecked`. In debug builds, the target
+    /// is checked to stay in place when `pointer` is moved, which catches
+    /// pointers that store their target inline.
+    ///
+    /// # Safety
+    ///
+    /// See [`Pin::new_unchecked`].
+    ///
+    /// [`Pin::new_unchecked`]: https://doc.rust-lang.org/std/pin/struct.Pin.html#method.new_unchecked
+    unsafe fn pin_unchecked(pointer: P) -> Self;
+
+    /// Unpins `self`, returning its pointer.
+    ///
+    /// This is equivalent to `Pin::into_inner_unchecked`. In debug builds, the
+    /// target is checked to stay in place when the pointer is moved out.
+    ///
+    /// # Safety
+    ///
+    /// See [`Pin::into_inner_unchecked`].
+    ///
+    /// [`Pin::into_inner_unchecked`]: https://doc.rust-lang.org/std/pin/struct.Pin.html#method.into_inner_unchecked
+    unsafe fn unpin_unchecked(self) -> P;
+
+    /// Projects `self` onto a part of its target.
+    ///
+    /// This is equivalent to `Pin::map_unchecked`. In debug builds, the result
+    /// of `f` is checked to lie within the pinned value, which catches
+    /// projections that escape to unpinned memory.
+    ///
+    /// # Safety
+    ///
+    /// See [`Pin::map_unchecked`].
+    ///
+    /// [`Pin::map_unchecked`]: https://doc.rust-lang.org/std/pin/struct.Pin.html#method.map_unchecked
+    unsafe fn project_unchecked<'a, U, F>(self, f: F) -> Pin<&'a U>
+        where
+            P: Into<&'a P::Target>,
+            P::Target: 'a,
+            U: ?Sized,
+            F: FnOnce(&P::Target) -> &U;
+
+    /// Projects `self` onto a mutable part of its target.
+    ///
+    /// This is equivalent to `Pin::map_unchecked_mut`. In debug builds, the
+    /// result of `f` is checked to lie within the pinned value, which catches
+    /// projections that escape to unpinned memory.
+    ///
+    /// # Safety
+    ///
+    /// See [`Pin::map_unchecked_mut`].
+    ///
+    /// [`Pin::map_unchecked_mut`]: https://doc.rust-lang.org/std/pin/struct.Pin.html#method.map_unchecked_mut
+    unsafe fn project_unchecked_mut<'a, U, F>(self, f: F) -> Pin<&'a mut U>
+        where
+            P: DerefMut + Into<&'a mut P::Target>,
+            P::Target: 'a,
+            U: ?Sized,
+            F: FnOnce(&mut P::Target) -> &mut U;
+}
+
+impl<P: Deref> UnsafePin<P> for Pin<P> {
+    inline_policy! {
+        unsafe fn pin_unchecked(pointer: P) -> Pin<P> {
+            let before = byte_range(&*pointer);
+            let pin = Pin::new_unchecked(pointer);
+            check_range(before, byte_range(&*pin));
+            pin
+        }
+
+        unsafe fn unpin_unchecked(self) -> P {
+            let before = byte_range(&*self);
+            let pointer = Pin::into_inner_unchecked(self);
+            check_range(before, byte_range(&*pointer));
+            pointer
+        }
+
+        unsafe fn project_unchecked<'a, U, F>(self, f: F) -> Pin<&'a U>
+            where
+                P: Into<&'a P::Target>,
+                P::Target: 'a,
+                U: ?Sized,
+                F: FnOnce(&P::Target) -> &U
+        {
+            let outer = Pin::into_inner_unchecked(self).into();
+            let inner = f(outer);
+            check_range(byte_range(outer), byte_range(inner));
+            Pin::new_unchecked(inner)
+        }
+
+        unsafe fn project_unchecked_mut<'a, U, F>(self, f: F) -> Pin<&'a mut U>
+            where
+                P: DerefMut + Into<&'a mut P::Target>,
+                P::Target: 'a,
+                U: ?Sized,
+                F: FnOnce(&mut P::Target) -> &mut U
+        {
+            let outer = Pin::into_inner_unchecked(self).into();
+            let range = byte_range(outer);
+            let inner = f(outer);
+            check_range(range, byte_range(inner));
+            Pin::new_unchecked(inner)
+        }
+    }
+}
+
+#[inline(always)]
+fn byte_range<T: ?Sized>(value: &T) -> (usize, usize) {
+    let start = value as *const T as *const u8 as usize;
+    (start, start + mem::size_of_val(value))
+}
+
+#[inline(always)]
+unsafe fn check_range(outer: (usize, usize), inner: (usize, usize)) {
+    if CHECKED && (inner.0 < outer.0 || inner.1 > outer.1) {
+        unreachable();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Pair {
+        a: u32,
+        b: u64,
+    }
+
+    /// A pointer that stores its target inline, so moving it moves the target.
+    struct Inline([u64; 4]);
+
+    impl Deref for Inline {
+        type Target = [u64; 4];
+
+        fn deref(&self) -> &[u64; 4] {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn pin_success() {
+        let mut x = 1;
+        unsafe {
+            let pin = Pin::pin_unchecked(&mut x);
+            *pin.unpin_unchecked() = 2;
+        }
+        assert_eq!(x, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn pin_failure() {
+        unsafe { Pin::pin_unchecked(Inline([0; 4])) };
+    }
+
+    #[test]
+    fn project_success() {
+        let mut pair = Pair { a: 1, b: 2 };
+        unsafe {
+            let pin = Pin::pin_unchecked(&mut pair);
+            *pin.project_unchecked_mut(|p| &mut p.b) = 3;
+            let pin = Pin::pin_unchecked(&pair);
+            assert_eq!(*pin.project_unchecked(|p| &p.a), 1);
+            assert_eq!(pin.unpin_unchecked().b, 3);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn project_failure() {
+        static OUTSIDE: u32 = 0;
+        let pair = Pair { a: 1, b: 2 };
+        unsafe { Pin::pin_unchecked(&pair).project_unchecked(|_| &OUTSIDE) };
+    }
+}
@@ -0,0 +1,68 @@
+//! Re-exports every trait of this crate that is enabled.
+//!
+//! This replaces a list of per-module imports with one glob import. Traits of
+//! disabled modules are left out.
+//!
+//! ```rust
+//! use unsafe_unwrap::prelude::*;
+//!
+//! let x: Result<u8, ()> = Ok(1);
+//! assert_eq!(unsafe { x.expect_unchecked("`x` is `Ok`") }, 1);
+//! ```
+
+pub use {UnsafeBound, UnsafeFromPrimitive, UnsafeUnwrap, UnsafeUnwrapDeep};
+pub use UnsafeUnwrapExt;
+#[cfg(feature = "try_trait")]
+pub use UnsafeUnwrapTry;
+#[cfg(feature = "alloc")]
+pub use UnsafeCow;
+
+#[cfg(feature = "collections")]
+pub use collections::{
+    UnsafeBTreeMap, UnsafeBinaryHeap, UnsafeHashMap, UnsafeHashSet,
+    UnsafeString, UnsafeVec, UnsafeVecDeque,
+};
+#[cfg(feature = "hints")]
+pub use hints::{ColdOption, ColdResult};
+pub use index::{UnsafeIndex, UnsafeIndexMut};
+#[cfg(feature = "std")]
+pub use io::UnsafeIntoInner;
+#[cfg(feature = "iter")]
+pub use iter::UnsafeIterator;
+pub use never::IntoOk;
+pub use num::{UnsafeInt, UnsafeUint};
+pub use pin::UnsafePin;
+#[cfg(feature = "option")]
+pub use option::UnsafeOption;
+#[cfg(feature = "std")]
+pub use process::{UnsafeChild, UnsafeExitStatus};
+#[cfg(feature = "result")]
+pub use result::UnsafeResult;
+#[cfg(feature = "slice")]
+pub use slice::{NicheOptimized, UnsafeSlice, UnsafeUnwrapAll};
+#[cfg(feature = "str")]
+pub use str::UnsafeChar;
+#[cfg(feature = "sync")]
+pub use sync::{UnsafeAtomic, UnsafeMutex, UnsafeOnce, UnsafeRwLock};
+#[cfg(feature = "std")]
+pub use thread::{UnsafeLocalKey, UnsafeLocalRefCell};
+pub use time::UnsafeDuration;
+#[cfg(feature = "std")]
+pub use time::{UnsafeInstant, UnsafeSystemTime};
+
+#[cfg(feature = "anyhow")]
+pub use interop::anyhow::UnsafeError;
+#[cfg(feature = "bytes")]
+pub use interop::bytes::UnsafeBuf;
+#[cfg(feature = "crossbeam")]
+pub use interop::crossbeam::{UnsafeArrayQueue, UnsafeSegQueue};
+#[cfg(feature = "dashmap")]
+pub use interop::dashmap::UnsafeDashMap;
+#[cfg(feature = "hashbrown")]
+pub use interop::hashbrown::UnsafeRawEntry;
+#[cfg(feature = "nb")]
+pub use interop::nb::UnsafeNbResult;
+#[cfg(feature = "nom")]
+pub use interop::nom::UnsafeIResult;
+#[cfg(feature = "rayon")]
+pub use interop::rayon::UnsafeParallelIterator;
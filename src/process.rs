@@ -0,0 +1,117 @@
+//! Unchecked inspection of child processes.
+//!
+//! These are intended for tools that treat a failed or signaled child as
+//! fatal elsewhere, such as by checking the status once up front. This module
+//! requires the `std` feature.
+
+use std::process::{Child, ExitStatus};
+
+use {UnsafeUnwrap, assume};
+
+/// Unchecked inspection of an `ExitStatus` known to be a normal exit.
+pub trait UnsafeExitStatus {
+    /// Returns the exit code, assuming that the process was not terminated
+    /// by a signal.
+    ///
+    /// # Safety
+    ///
+    /// `self.code()` must be `Some`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(unix)] {
+    /// use std::process::Command;
+    /// use unsafe_unwrap::process::UnsafeExitStatus;
+    ///
+    /// let status = Command::new("sh").args(["-c", "exit 3"]).status();
+    /// let status = status.unwrap();
+    /// assert_eq!(unsafe { status.code_unchecked() }, 3);
+    /// # }
+    /// ```
+    unsafe fn code_unchecked(&self) -> i32;
+
+    /// Assumes that the process exited successfully.
+    ///
+    /// This lets the optimizer remove later failure handling that depends on
+    /// the status.
+    ///
+    /// # Safety
+    ///
+    /// `self.success()` must be `true`. This is checked in debug builds.
+    unsafe fn assume_success(&self);
+}
+
+impl UnsafeExitStatus for ExitStatus {
+    #[inline]
+    unsafe fn code_unchecked(&self) -> i32 {
+        self.code().unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn assume_success(&self) {
+        assume(self.success())
+    }
+}
+
+/// Unchecked waiting on a `Child`.
+pub trait UnsafeChild {
+    /// Waits for the child to exit, assuming that waiting does not fail.
+    ///
+    /// # Safety
+    ///
+    /// `self.wait()` must be `Ok`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(unix)] {
+    /// use std::process::Command;
+    /// use unsafe_unwrap::process::{UnsafeChild, UnsafeExitStatus};
+    ///
+    /// let mut child = Command::new("true").spawn().unwrap();
+    /// let status = unsafe { child.wait_unchecked() };
+    /// unsafe { status.assume_success() };
+    /// # }
+    /// ```
+    unsafe fn wait_unchecked(&mut self) -> ExitStatus;
+}
+
+impl UnsafeChild for Child {
+    #[inline]
+    unsafe fn wait_unchecked(&mut self) -> ExitStatus {
+        self.wait().unsafe_unwrap()
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn sh(script: &str) -> Child {
+        Command::new("sh").args(["-c", script]).spawn().unwrap()
+    }
+
+    #[test]
+    fn exit_status_success() {
+        unsafe {
+            let status = sh("exit 0").wait_unchecked();
+            status.assume_success();
+            assert_eq!(status.code_unchecked(), 0);
+            assert_eq!(sh("exit 7").wait_unchecked().code_unchecked(), 7);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn code_failure() {
+        unsafe { sh("kill -9 $$").wait_unchecked().code_unchecked() };
+    }
+
+    #[test]
+    #[should_panic]
+    fn assume_success_failure() {
+        unsafe { sh("exit 1").wait_unchecked().assume_success() };
+    }
+}
@@ -0,0 +1,154 @@
+//! Unchecked operations specific to `Result`.
+//!
+//! This module requires the `result` feature, which is enabled by default.
+//! Its trait is also re-exported from the crate root.
+
+use core::mem::ManuallyDrop;
+use core::ptr;
+
+use {UnsafeUnwrap, unreachable};
+
+/// Unchecked operations specific to `Result`.
+pub trait UnsafeResult<T, E> {
+    /// Maps the `Ok` value with `f` without checking that it exists, keeping
+    /// the result wrapped in `Ok`.
+    ///
+    /// Unlike unwrapping, this lets the assumption be carried through a chain
+    /// of adapters, and still allows the result to be used with `?`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ok`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::num::ParseIntError;
+    /// use unsafe_unwrap::UnsafeResult;
+    ///
+    /// fn double(digits: &str) -> Result<u64, ParseIntError> {
+    ///     let n = digits.parse::<u64>();
+    ///     Ok(unsafe { n.map_unchecked(|n| n * 2) }?)
+    /// }
+    /// # assert_eq!(double("21"), Ok(42));
+    /// ```
+    unsafe fn map_unchecked<U, F>(self, f: F) -> Result<U, E>
+        where F: FnOnce(T) -> U;
+
+    /// Calls `f` with the `Ok` value without checking that it exists,
+    /// returning its result.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ok`. This is checked in debug builds.
+    unsafe fn and_then_unchecked<U, F>(self, f: F) -> Result<U, E>
+        where F: FnOnce(T) -> Result<U, E>;
+
+    /// Unsafely moves the `Ok` value out of `self` without checking, emitting
+    /// no drop glue for `E`.
+    ///
+    /// Unlike `unsafe_unwrap()`, this cannot pull the destructor of `E` and
+    /// the symbols it references, such as the deallocator, into the caller.
+    /// In debug builds, an `Err` value is leaked before panicking.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ok`. This is checked in debug builds.
+    unsafe fn unsafe_unwrap_forget_err(self) -> T;
+
+    /// Unsafely moves the `Err` value out of `self` without checking.
+    ///
+    /// As with `UnsafeUnwrap::unwrap_unchecked`, the inherent method of the
+    /// same name takes precedence with method syntax.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Err`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::UnsafeResult;
+    ///
+    /// let res = "x".parse::<u8>();
+    /// let err = unsafe { UnsafeResult::unwrap_err_unchecked(res) };
+    /// assert_eq!(*err.kind(), std::num::IntErrorKind::InvalidDigit);
+    /// ```
+    unsafe fn unwrap_err_unchecked(self) -> E;
+}
+
+impl<T, E> UnsafeResult<T, E> for Result<T, E> {
+    #[inline]
+    unsafe fn map_unchecked<U, F>(self, f: F) -> Result<U, E>
+        where F: FnOnce(T) -> U
+    {
+        Ok(f(self.unsafe_unwrap()))
+    }
+
+    #[inline]
+    unsafe fn and_then_unchecked<U, F>(self, f: F) -> Result<U, E>
+        where F: FnOnce(T) -> Result<U, E>
+    {
+        f(self.unsafe_unwrap())
+    }
+
+    #[inline]
+    unsafe fn unsafe_unwrap_forget_err(self) -> T {
+        let this = ManuallyDrop::new(self);
+        match *this {
+            Ok(ref x) => ptr::read(x),
+            Err(_) => unreachable(),
+        }
+    }
+
+    #[inline]
+    #[cfg_attr(feature = "failure-hook", track_caller)]
+    unsafe fn unwrap_err_unchecked(self) -> E {
+        if let Err(e) = self { e } else { unreachable() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn result_map_success() {
+        unsafe {
+            let x: Result<_, ()> = Ok(1);
+            assert_eq!(x.map_unchecked(|x| x + 1), Ok(2));
+            assert_eq!(x.and_then_unchecked(|_| Err::<(), _>(())), Err(()));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn result_map_failure() {
+        let _ = unsafe { Err::<u8, _>(0).and_then_unchecked(Ok) };
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn result_forget_err_success() {
+        let x: Result<_, alloc::string::String> = Ok(alloc::vec![1, 2]);
+        assert_eq!(unsafe { x.unsafe_unwrap_forget_err() }, [1, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn result_forget_err_failure() {
+        unsafe { Err::<u8, _>(0).unsafe_unwrap_forget_err() };
+    }
+
+    #[test]
+    fn result_unwrap_err_success() {
+        let x = Err::<(), u8>(1);
+        assert_eq!(unsafe { UnsafeResult::unwrap_err_unchecked(x) }, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn result_unwrap_err_failure() {
+        unsafe { UnsafeResult::unwrap_err_unchecked(Ok::<u8, u8>(0)) };
+    }
+}
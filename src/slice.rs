@@ -0,0 +1,716 @@
+//! Unchecked operations on slices.
+
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+use core::slice::{ChunksExact, ChunksExactMut};
+use core::{mem, num, ptr, slice};
+
+use hints::assume_divisible_by;
+use {CHECKED, UnsafeUnwrap, assume, unreachable};
+
+/// Unchecked operations on slices that assume preconditions which the
+/// standard methods would otherwise check.
+pub trait UnsafeSlice<T> {
+    /// Copies all elements from `src` into `self` without checking that the
+    /// lengths are equal.
+    ///
+    /// This compiles to a bare `memcpy` in optimized builds.
+    ///
+    /// # Safety
+    ///
+    /// `self` and `src` must have the same length. This is checked in debug
+    /// builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::slice::UnsafeSlice;
+    ///
+    /// let mut dst = [0u8; 4];
+    /// unsafe { dst.copy_from_slice_unchecked(b"abcd") };
+    /// assert_eq!(&dst, b"abcd");
+    /// ```
+    unsafe fn copy_from_slice_unchecked(&mut self, src: &[T]) where T: Copy;
+
+    /// Clones all elements from `src` into `self` without checking that the
+    /// lengths are equal.
+    ///
+    /// # Safety
+    ///
+    /// `self` and `src` must have the same length. This is checked in debug
+    /// builds.
+    unsafe fn clone_from_slice_unchecked(&mut self, src: &[T]) where T: Clone;
+
+    /// Converts `self` into an array reference without checking its length.
+    ///
+    /// # Safety
+    ///
+    /// `self.len()` must equal `N`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::slice::UnsafeSlice;
+    ///
+    /// let header = [0xca, 0xfe, 0xba, 0xbe, 0x00, 0x01];
+    /// let magic = unsafe { header[..4].as_array_unchecked::<4>() };
+    /// assert_eq!(u32::from_be_bytes(*magic), 0xcafebabe);
+    /// ```
+    unsafe fn as_array_unchecked<const N: usize>(&self) -> &[T; N];
+
+    /// Converts `self` into a mutable array reference without checking its
+    /// length.
+    ///
+    /// # Safety
+    ///
+    /// `self.len()` must equal `N`. This is checked in debug builds.
+    unsafe fn as_array_unchecked_mut<const N: usize>(&mut self) -> &mut [T; N];
+
+    /// Returns an iterator over `chunk_size`-element chunks of `self`,
+    /// assuming that there is no remainder.
+    ///
+    /// Unlike the inherent `as_chunks_unchecked` of slices, the chunk size
+    /// need not be a constant.
+    ///
+    /// # Safety
+    ///
+    /// `chunk_size` must be non-zero and `self.len()` must be a multiple of
+    /// it. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::slice::UnsafeSlice;
+    ///
+    /// let samples = [1i16, -1, 2, -2, 3, -3];
+    /// let channels = 2;
+    /// let left: Vec<i16> = unsafe { samples.chunks_exact_unchecked(channels) }
+    ///     .map(|frame| frame[0])
+    ///     .collect();
+    /// assert_eq!(left, [1, 2, 3]);
+    /// ```
+    unsafe fn chunks_exact_unchecked(&self, chunk_size: usize) -> ChunksExact<'_, T>;
+
+    /// Returns an iterator over mutable `chunk_size`-element chunks of
+    /// `self`, assuming that there is no remainder.
+    ///
+    /// # Safety
+    ///
+    /// `chunk_size` must be non-zero and `self.len()` must be a multiple of
+    /// it. This is checked in debug builds.
+    unsafe fn chunks_exact_unchecked_mut(
+        &mut self,
+        chunk_size: usize,
+    ) -> ChunksExactMut<'_, T>;
+
+    /// Reinterprets `self` as a slice of `U` without checking its alignment
+    /// or size.
+    ///
+    /// Unlike [`align_to`], which may return a non-empty prefix or suffix
+    /// even for aligned input, this always reinterprets the whole slice.
+    ///
+    /// `U` must not be zero-sized, which is checked at compile time.
+    ///
+    /// # Safety
+    ///
+    /// The requirements of [`align_to`] must hold. In addition, `self` must
+    /// start at an address aligned for `U` and span a multiple of
+    /// `size_of::<U>()` bytes. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::slice::UnsafeSlice;
+    ///
+    /// let words = [0x0102_0304u32; 4];
+    /// let bytes: &[u8] = unsafe { words.align_to_unchecked() };
+    /// let back: &[u32] = unsafe { bytes.align_to_unchecked() };
+    /// assert_eq!(back, words);
+    /// ```
+    ///
+    /// [`align_to`]: https://doc.rust-lang.org/std/primitive.slice.html#method.align_to
+    unsafe fn align_to_unchecked<U>(&self) -> &[U];
+
+    /// Reinterprets `self` as a mutable slice of `U` without checking its
+    /// alignment or size.
+    ///
+    /// `U` must not be zero-sized, which is checked at compile time.
+    ///
+    /// # Safety
+    ///
+    /// The requirements of [`align_to_mut`] must hold. In addition, `self`
+    /// must start at an address aligned for `U` and span a multiple of
+    /// `size_of::<U>()` bytes. This is checked in debug builds.
+    ///
+    /// [`align_to_mut`]: https://doc.rust-lang.org/std/primitive.slice.html#method.align_to_mut
+    unsafe fn align_to_unchecked_mut<U>(&mut self) -> &mut [U];
+}
+
+impl<T> UnsafeSlice<T> for [T] {
+    #[inline]
+    unsafe fn copy_from_slice_unchecked(&mut self, src: &[T]) where T: Copy {
+        assume(self.len() == src.len());
+        ptr::copy_nonoverlapping(src.as_ptr(), self.as_mut_ptr(), self.len());
+    }
+
+    #[inline]
+    unsafe fn clone_from_slice_unchecked(&mut self, src: &[T]) where T: Clone {
+        assume(self.len() == src.len());
+        for (dst, src) in self.iter_mut().zip(src) {
+            dst.clone_from(src);
+        }
+    }
+
+    #[inline]
+    unsafe fn as_array_unchecked<const N: usize>(&self) -> &[T; N] {
+        assume(self.len() == N);
+        &*(self.as_ptr() as *const [T; N])
+    }
+
+    #[inline]
+    unsafe fn as_array_unchecked_mut<const N: usize>(&mut self) -> &mut [T; N] {
+        assume(self.len() == N);
+        &mut *(self.as_mut_ptr() as *mut [T; N])
+    }
+
+    #[inline]
+    unsafe fn chunks_exact_unchecked(&self, chunk_size: usize) -> ChunksExact<'_, T> {
+        assume_divisible_by(self, chunk_size);
+        self.chunks_exact(chunk_size)
+    }
+
+    #[inline]
+    unsafe fn chunks_exact_unchecked_mut(
+        &mut self,
+        chunk_size: usize,
+    ) -> ChunksExactMut<'_, T> {
+        assume_divisible_by(self, chunk_size);
+        self.chunks_exact_mut(chunk_size)
+    }
+
+    #[inline]
+    unsafe fn align_to_unchecked<U>(&self) -> &[U] {
+        let len = reinterpreted_len::<T, U>(self);
+        slice::from_raw_parts(self.as_ptr() as *const U, len)
+    }
+
+    #[inline]
+    unsafe fn align_to_unchecked_mut<U>(&mut self) -> &mut [U] {
+        let len = reinterpreted_len::<T, U>(self);
+        slice::from_raw_parts_mut(self.as_mut_ptr() as *mut U, len)
+    }
+}
+
+/// Returns the number of `U` elements spanned by `slice`, assuming that it is
+/// aligned for `U` and spans a multiple of its size.
+#[inline(always)]
+unsafe fn reinterpreted_len<T, U>(slice: &[T]) -> usize {
+    let size = const {
+        assert!(mem::size_of::<U>() != 0, "`U` must not be zero-sized");
+        mem::size_of::<U>()
+    };
+    let bytes = mem::size_of_val(slice);
+    assume(slice.as_ptr().cast::<U>().is_aligned() && bytes.is_multiple_of(size));
+    bytes / size
+}
+
+/// A type whose `Option` is guaranteed to have the same layout as itself,
+/// with `None` in the niche.
+///
+/// # Safety
+///
+/// `Option<Self>` must have the same size and alignment as `Self`, and every
+/// `Some(x)` must have the same representation as `x`, as `core::option`
+/// guarantees for references, `NonNull`, and `Box` of sized types, and for
+/// the `NonZero` integers.
+pub unsafe trait NicheOptimized {}
+
+unsafe impl<T> NicheOptimized for &T {}
+unsafe impl<T> NicheOptimized for &mut T {}
+unsafe impl<T> NicheOptimized for ptr::NonNull<T> {}
+#[cfg(feature = "alloc")]
+unsafe impl<T> NicheOptimized for alloc::boxed::Box<T> {}
+
+macro_rules! impl_niche_optimized {
+    ($($t:ident)+) => {
+        $(unsafe impl NicheOptimized for num::$t {})+
+    };
+}
+
+impl_niche_optimized! {
+    NonZeroU8 NonZeroU16 NonZeroU32 NonZeroU64 NonZeroU128 NonZeroUsize
+    NonZeroI8 NonZeroI16 NonZeroI32 NonZeroI64 NonZeroI128 NonZeroIsize
+}
+
+/// Unwrapping of every element of a slice of `Option`s.
+pub trait UnsafeUnwrapAll<T> {
+    /// Reinterprets `self` as a slice of the inner values without checking
+    /// that every element is `Some`.
+    ///
+    /// This is free in optimized builds, since `Option<T>` has the same
+    /// layout as `T`. For other types, use `unsafe_unwrap_each()`.
+    ///
+    /// # Safety
+    ///
+    /// Every element must be `Some`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::num::NonZeroU32;
+    /// use unsafe_unwrap::slice::UnsafeUnwrapAll;
+    ///
+    /// let ids = [NonZeroU32::new(4), NonZeroU32::new(2)];
+    /// // The column's metadata says that it has no nulls.
+    /// let ids: &[NonZeroU32] = unsafe { ids.unsafe_unwrap_all() };
+    /// assert_eq!(ids[1].get(), 2);
+    /// ```
+    unsafe fn unsafe_unwrap_all(&self) -> &[T] where T: NicheOptimized;
+
+    /// Reinterprets `self` as a mutable slice of the inner values without
+    /// checking that every element is `Some`.
+    ///
+    /// # Safety
+    ///
+    /// Every element must be `Some`. This is checked in debug builds.
+    unsafe fn unsafe_unwrap_all_mut(&mut self) -> &mut [T]
+        where T: NicheOptimized;
+
+    /// Returns an iterator over the inner values without checking that every
+    /// element is `Some`.
+    ///
+    /// This is the fallback of `unsafe_unwrap_all()` for any `T`.
+    ///
+    /// # Safety
+    ///
+    /// Every element must be `Some`. This is checked in debug builds, as each
+    /// element is reached.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::slice::UnsafeUnwrapAll;
+    ///
+    /// let prices = [Some(1.5), Some(2.0)];
+    /// let total: f64 = unsafe { prices.unsafe_unwrap_each() }.sum();
+    /// assert_eq!(total, 3.5);
+    /// ```
+    unsafe fn unsafe_unwrap_each(&self) -> UnwrapEach<'_, T>;
+}
+
+impl<T> UnsafeUnwrapAll<T> for [Option<T>] {
+    #[inline]
+    unsafe fn unsafe_unwrap_all(&self) -> &[T] where T: NicheOptimized {
+        if CHECKED && self.iter().any(Option::is_none) {
+            unreachable();
+        }
+        slice::from_raw_parts(self.as_ptr() as *const T, self.len())
+    }
+
+    #[inline]
+    unsafe fn unsafe_unwrap_all_mut(&mut self) -> &mut [T]
+        where T: NicheOptimized
+    {
+        if CHECKED && self.iter().any(Option::is_none) {
+            unreachable();
+        }
+        slice::from_raw_parts_mut(self.as_mut_ptr() as *mut T, self.len())
+    }
+
+    #[inline]
+    unsafe fn unsafe_unwrap_each(&self) -> UnwrapEach<'_, T> {
+        UnwrapEach { iter: self.iter() }
+    }
+}
+
+/// A slice that is known to be sorted in ascending order.
+///
+/// The sortedness is verified once upon construction in debug builds and is
+/// otherwise trusted. It provides logarithmic `contains` and `equal_range`,
+/// constant-time `min` and `max`, and merging and deduplication without
+/// allocating. Its searches also assume that the indices they return are in
+/// bounds, so that indexing with them elides the bounds check.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::slice::AssumeSorted;
+///
+/// let index = unsafe { AssumeSorted::new(&[1, 3, 3, 7][..]) };
+///
+/// assert!(index.contains(&7));
+/// assert_eq!(index.binary_search(&7), Ok(3));
+/// assert_eq!(index.partition_point(|&x| x < 3), 1);
+/// assert_eq!(index.equal_range(&3), 1..3);
+/// assert_eq!(index.unique().count(), 3);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct AssumeSorted<'a, T: 'a> {
+    slice: &'a [T],
+}
+
+impl<'a, T> Clone for AssumeSorted<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> Copy for AssumeSorted<'a, T> {}
+
+impl<'a, T> AssumeSorted<'a, T> {
+    /// Returns the underlying slice.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [T] {
+        self.slice
+    }
+
+    /// Returns the smallest element, or `None` if the slice is empty.
+    #[inline]
+    pub fn min(&self) -> Option<&'a T> {
+        self.slice.first()
+    }
+
+    /// Returns the largest element, or `None` if the slice is empty.
+    #[inline]
+    pub fn max(&self) -> Option<&'a T> {
+        self.slice.last()
+    }
+
+    /// Returns the index of the first element for which `pred` is `false`.
+    ///
+    /// See [`slice::partition_point`] for details. The result is assumed to
+    /// be at most `len()`.
+    ///
+    /// [`slice::partition_point`]: https://doc.rust-lang.org/std/primitive.slice.html#method.partition_point
+    #[inline]
+    pub fn partition_point<P>(&self, pred: P) -> usize
+        where P: FnMut(&T) -> bool
+    {
+        let i = self.slice.partition_point(pred);
+        unsafe { assume(i <= self.slice.len()) };
+        i
+    }
+}
+
+impl<'a, T: Ord> AssumeSorted<'a, T> {
+    /// Wraps `slice` without checking that it is sorted.
+    ///
+    /// # Safety
+    ///
+    /// `slice` must be sorted in ascending order. This is checked in debug
+    /// builds.
+    #[inline]
+    pub unsafe fn new(slice: &'a [T]) -> Self {
+        if CHECKED && !slice.is_sorted() {
+            unreachable();
+        }
+        AssumeSorted { slice }
+    }
+
+    /// Binary searches for `x`.
+    ///
+    /// See [`slice::binary_search`] for details. A found index is assumed to
+    /// be less than `len()`, and an insertion index at most `len()`.
+    ///
+    /// [`slice::binary_search`]: https://doc.rust-lang.org/std/primitive.slice.html#method.binary_search
+    #[inline]
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize> {
+        let result = self.slice.binary_search(x);
+        let len = self.slice.len();
+        unsafe {
+            match result {
+                Ok(i) => assume(i < len),
+                Err(i) => assume(i <= len),
+            }
+        }
+        result
+    }
+
+    /// Returns `true` if the slice contains `x`.
+    ///
+    /// Unlike `<[T]>::contains`, this is a binary search rather than a
+    /// linear scan.
+    #[inline]
+    pub fn contains(&self, x: &T) -> bool {
+        self.binary_search(x).is_ok()
+    }
+
+    /// Returns the range of indices whose elements equal `x`, in logarithmic
+    /// time.
+    #[inline]
+    pub fn equal_range(&self, x: &T) -> core::ops::Range<usize> {
+        let start = self.partition_point(|y| y < x);
+        let len = self.slice[start..].partition_point(|y| y == x);
+        start..(start + len)
+    }
+
+    /// Returns an iterator over the elements of `self` and `other` in sorted
+    /// order.
+    ///
+    /// Equal elements are yielded from `self` first.
+    #[inline]
+    pub fn merge(self, other: AssumeSorted<'a, T>) -> Merge<'a, T> {
+        Merge { a: self.slice, b: other.slice }
+    }
+
+    /// Returns an iterator over the elements of `self` with duplicates
+    /// removed.
+    ///
+    /// Because the slice is sorted, each element only needs to be compared
+    /// with its predecessor.
+    #[inline]
+    pub fn unique(self) -> Unique<'a, T> {
+        Unique { slice: self.slice }
+    }
+}
+
+impl<'a, T> IntoIterator for AssumeSorted<'a, T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.iter()
+    }
+}
+
+/// An iterator over two sorted slices in sorted order.
+///
+/// Created by [`AssumeSorted::merge`](struct.AssumeSorted.html#method.merge).
+#[derive(Clone, Debug)]
+pub struct Merge<'a, T: 'a> {
+    a: &'a [T],
+    b: &'a [T],
+}
+
+impl<'a, T: Ord> Iterator for Merge<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        let take_b = match (self.a.first(), self.b.first()) {
+            (Some(a), Some(b)) => b.cmp(a) == Ordering::Less,
+            (Some(_), None) => false,
+            (None, Some(_)) => true,
+            (None, None) => return None,
+        };
+        let side = if take_b { &mut self.b } else { &mut self.a };
+        // The chosen side was matched as non-empty above.
+        let (first, rest) = unsafe { side.split_first().unsafe_unwrap() };
+        *side = rest;
+        Some(first)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.a.len() + self.b.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: Ord> ExactSizeIterator for Merge<'a, T> {}
+
+impl<'a, T: Ord> FusedIterator for Merge<'a, T> {}
+
+/// An iterator over the distinct elements of a sorted slice.
+///
+/// Created by [`AssumeSorted::unique`](struct.AssumeSorted.html#method.unique).
+#[derive(Clone, Debug)]
+pub struct Unique<'a, T: 'a> {
+    slice: &'a [T],
+}
+
+impl<'a, T: Ord> Iterator for Unique<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        let (first, rest) = self.slice.split_first()?;
+        let run = rest.iter().take_while(|x| *x == first).count();
+        self.slice = &rest[run..];
+        Some(first)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (!self.slice.is_empty() as usize, Some(self.slice.len()))
+    }
+}
+
+impl<'a, T: Ord> FusedIterator for Unique<'a, T> {}
+
+/// An iterator over the inner values of a slice of `Option`s.
+///
+/// Created by
+/// [`UnsafeUnwrapAll::unsafe_unwrap_each`](trait.UnsafeUnwrapAll.html#tymethod.unsafe_unwrap_each).
+#[derive(Clone, Debug)]
+pub struct UnwrapEach<'a, T: 'a> {
+    iter: slice::Iter<'a, Option<T>>,
+}
+
+impl<'a, T> Iterator for UnwrapEach<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        // Upheld by the caller of `unsafe_unwrap_each`.
+        self.iter.next().map(|x| unsafe { x.as_ref().unsafe_unwrap() })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for UnwrapEach<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.iter.next_back().map(|x| unsafe { x.as_ref().unsafe_unwrap() })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for UnwrapEach<'a, T> {}
+
+impl<'a, T> FusedIterator for UnwrapEach<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copy_from_slice_success() {
+        let mut dst = [0; 3];
+        unsafe { dst.copy_from_slice_unchecked(&[1, 2, 3]) };
+        assert_eq!(dst, [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_from_slice_failure() {
+        unsafe { [0; 3].copy_from_slice_unchecked(&[1, 2]) };
+    }
+
+    #[test]
+    fn clone_from_slice_success() {
+        let mut dst = [None, None];
+        unsafe { dst.clone_from_slice_unchecked(&[Some(1), Some(2)]) };
+        assert_eq!(dst, [Some(1), Some(2)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clone_from_slice_failure() {
+        unsafe { [None; 3].clone_from_slice_unchecked(&[Some(1)]) };
+    }
+
+    #[test]
+    fn as_array_success() {
+        let mut bytes = [1u8, 2, 3, 4, 5];
+        unsafe {
+            assert_eq!(bytes[1..3].as_array_unchecked::<2>(), &[2, 3]);
+            bytes[3..].as_array_unchecked_mut::<2>()[1] = 0;
+        }
+        assert_eq!(bytes, [1, 2, 3, 4, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn as_array_failure() {
+        unsafe { [0u8; 3].as_array_unchecked::<4>() };
+    }
+
+    #[test]
+    fn chunks_exact_success() {
+        let mut bytes = [1u8, 2, 3, 4, 5, 6];
+        unsafe {
+            for chunk in bytes.chunks_exact_unchecked_mut(3) {
+                chunk[0] = 0;
+            }
+            let chunks = bytes.chunks_exact_unchecked(3);
+            assert!(chunks.remainder().is_empty());
+            assert!(chunks.eq([[0, 2, 3], [0, 5, 6]].iter()));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn chunks_exact_failure() {
+        let _ = unsafe { [0u8; 5].chunks_exact_unchecked(2) };
+    }
+
+    #[test]
+    fn align_to_success() {
+        let mut words = [0u32; 2];
+        unsafe {
+            let halves = words.align_to_unchecked_mut::<u16>();
+            halves[3] = 1;
+            assert_eq!(halves.align_to_unchecked::<u32>().len(), 2);
+        }
+        assert_eq!(words[1].to_ne_bytes()[2..], 1u16.to_ne_bytes());
+    }
+
+    #[test]
+    #[should_panic]
+    fn align_to_failure() {
+        let words = [0u32; 2];
+        unsafe {
+            let bytes = words.align_to_unchecked::<u8>();
+            bytes[1..].align_to_unchecked::<u16>();
+        }
+    }
+
+    #[test]
+    fn sorted_success() {
+        let a = unsafe { AssumeSorted::new(&[1, 2, 2, 5, 9][..]) };
+        let b = unsafe { AssumeSorted::new(&[0, 2, 6][..]) };
+
+        assert!(a.contains(&5) && !a.contains(&3));
+        assert_eq!((a.binary_search(&5), a.binary_search(&10)), (Ok(3), Err(5)));
+        assert_eq!(a.partition_point(|&x| x < 5), 3);
+        assert_eq!(a.equal_range(&2), 1..3);
+        assert_eq!(a.equal_range(&3), 3..3);
+        assert_eq!((a.min(), a.max()), (Some(&1), Some(&9)));
+
+        let merged = [0, 1, 2, 2, 2, 5, 6, 9];
+        assert!(a.merge(b).eq(merged.iter()));
+
+        let unique = [1, 2, 5, 9];
+        assert!(a.unique().eq(unique.iter()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn sorted_failure() {
+        unsafe { AssumeSorted::new(&[2, 1][..]) };
+    }
+
+    #[test]
+    fn unwrap_all_success() {
+        let mut xs = [num::NonZeroU8::new(1), num::NonZeroU8::new(2)];
+        unsafe {
+            xs.unsafe_unwrap_all_mut()[0] = num::NonZeroU8::MAX;
+            assert_eq!(xs.unsafe_unwrap_all()[0].get(), 255);
+            assert!(xs.unsafe_unwrap_each().eq(xs.unsafe_unwrap_all()));
+        }
+        let refs = [Some(&1), Some(&2)];
+        assert_eq!(unsafe { refs.unsafe_unwrap_all() }, [&1, &2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unwrap_all_failure() {
+        unsafe { [Some(&1), None].unsafe_unwrap_all() };
+    }
+
+    #[test]
+    #[should_panic]
+    fn unwrap_all_mut_failure() {
+        unsafe { [None, Some(&mut 1)].unsafe_unwrap_all_mut() };
+    }
+
+    #[test]
+    #[should_panic]
+    fn unwrap_each_failure() {
+        unsafe { [Some(1), None].unsafe_unwrap_each().count() };
+    }
+}
@@ -0,0 +1,367 @@
+//! Unchecked operations on strings.
+
+use core::fmt;
+use core::ops::Deref;
+use core::slice::SliceIndex;
+use core::str;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use {CHECKED, UnsafeUnwrap, assume, unreachable};
+
+/// Tells the optimizer that `s` only contains ASCII, returning it as an
+/// [`AsciiStr`].
+///
+/// Since every byte of an ASCII string is a character boundary, an
+/// `AsciiStr` can be sliced by byte index without UTF-8 boundary checks.
+///
+/// # Safety
+///
+/// `s` must only contain ASCII characters. This is checked in debug builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::str::assume_ascii;
+///
+/// let src = unsafe { assume_ascii("let x = 42;") };
+/// assert_eq!(src.slice(4..5), "x");
+/// assert_eq!(src.byte(8), b'4');
+/// ```
+///
+/// [`AsciiStr`]: struct.AsciiStr.html
+#[inline]
+pub unsafe fn assume_ascii(s: &str) -> &AsciiStr {
+    if CHECKED && !s.is_ascii() {
+        unreachable();
+    }
+    &*(s as *const str as *const AsciiStr)
+}
+
+/// Tells the optimizer that `idx` lies on a character boundary of `s`.
+///
+/// This removes the boundary checks of subsequent slicing at `idx`, such as
+/// `&s[..idx]` and `&s[idx..]`.
+///
+/// # Safety
+///
+/// `s.is_char_boundary(idx)` must be `true`. This is checked in debug builds.
+#[inline(always)]
+pub unsafe fn assume_char_boundary(s: &str, idx: usize) {
+    assume(s.is_char_boundary(idx));
+}
+
+/// A string slice that only contains ASCII characters.
+///
+/// Created by [`assume_ascii`](fn.assume_ascii.html).
+#[repr(transparent)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsciiStr(str);
+
+impl AsciiStr {
+    /// Returns the underlying string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the byte at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[inline]
+    pub fn byte(&self, idx: usize) -> u8 {
+        self.0.as_bytes()[idx]
+    }
+
+    /// Returns the subslice at the byte `range`, without checking for
+    /// character boundaries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    #[inline]
+    pub fn slice<R>(&self, range: R) -> &AsciiStr
+        where R: SliceIndex<[u8], Output = [u8]>
+    {
+        unsafe { Self::from_bytes(&self.0.as_bytes()[range]) }
+    }
+
+    /// Returns the subslice at the byte `range`, without checking for bounds
+    /// or character boundaries.
+    ///
+    /// # Safety
+    ///
+    /// `range` must be in bounds.
+    #[inline]
+    pub unsafe fn slice_unchecked<R>(&self, range: R) -> &AsciiStr
+        where R: SliceIndex<[u8], Output = [u8]>
+    {
+        Self::from_bytes(self.0.as_bytes().get_unchecked(range))
+    }
+
+    #[inline]
+    unsafe fn from_bytes(bytes: &[u8]) -> &AsciiStr {
+        &*(str::from_utf8_unchecked(bytes) as *const str as *const AsciiStr)
+    }
+}
+
+impl Deref for AsciiStr {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for AsciiStr {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for AsciiStr {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for AsciiStr {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.0 == **other
+    }
+}
+
+impl fmt::Debug for AsciiStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for AsciiStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// Digit conversions on `char` that assume the checked conversions succeed.
+pub trait UnsafeChar: Sized {
+    /// Converts `self` into a digit in the given `radix`, assuming it is one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is greater than 36, like `char::to_digit`.
+    ///
+    /// # Safety
+    ///
+    /// `self.to_digit(radix)` must be `Some`. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use unsafe_unwrap::str::UnsafeChar;
+    ///
+    /// let value = "7f".chars().fold(0, |acc, c| {
+    ///     acc * 16 + unsafe { c.to_digit_unchecked(16) }
+    /// });
+    /// assert_eq!(value, 0x7f);
+    /// ```
+    unsafe fn to_digit_unchecked(self, radix: u32) -> u32;
+
+    /// Converts `num` into a digit character in the given `radix`, assuming
+    /// it is in range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is greater than 36, like `char::from_digit`.
+    ///
+    /// # Safety
+    ///
+    /// `char::from_digit(num, radix)` must be `Some`. This is checked in
+    /// debug builds.
+    unsafe fn from_digit_unchecked(num: u32, radix: u32) -> Self;
+}
+
+impl UnsafeChar for char {
+    #[inline]
+    unsafe fn to_digit_unchecked(self, radix: u32) -> u32 {
+        self.to_digit(radix).unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn from_digit_unchecked(num: u32, radix: u32) -> Self {
+        char::from_digit(num, radix).unsafe_unwrap()
+    }
+}
+
+/// Decodes UTF-16 code units into `char`s, assuming that they contain no
+/// unpaired surrogates.
+///
+/// Unlike `char::decode_utf16`, the returned iterator yields `char` directly
+/// and only branches on whether a code unit starts a surrogate pair.
+///
+/// # Safety
+///
+/// Every high surrogate in `iter` must be immediately followed by a low
+/// surrogate, and every low surrogate must be immediately preceded by a high
+/// surrogate. This is checked in debug builds.
+///
+/// # Examples
+///
+/// ```rust
+/// use unsafe_unwrap::str::decode_utf16_unchecked;
+///
+/// let wide = [0x0068, 0xD834, 0xDD1E];
+/// let s: String = unsafe { decode_utf16_unchecked(wide.iter().cloned()) }
+///     .collect();
+/// assert_eq!(s, "h\u{1D11E}");
+/// ```
+#[inline]
+pub unsafe fn decode_utf16_unchecked<I>(
+    iter: I,
+) -> DecodeUtf16Unchecked<I::IntoIter>
+    where I: IntoIterator<Item = u16>
+{
+    DecodeUtf16Unchecked { iter: iter.into_iter() }
+}
+
+/// Decodes `v` into a `String`, assuming that it contains no unpaired
+/// surrogates.
+///
+/// # Safety
+///
+/// `String::from_utf16(v)` must be `Ok`. This is checked in debug builds.
+#[cfg(feature = "alloc")]
+#[inline]
+pub unsafe fn from_utf16_unchecked(v: &[u16]) -> String {
+    let mut s = String::with_capacity(v.len());
+    s.extend(decode_utf16_unchecked(v.iter().cloned()));
+    s
+}
+
+/// An iterator that decodes UTF-16 code units into `char`s without checking
+/// for unpaired surrogates.
+///
+/// Created by [`decode_utf16_unchecked`](fn.decode_utf16_unchecked.html).
+#[derive(Clone, Debug)]
+pub struct DecodeUtf16Unchecked<I> {
+    iter: I,
+}
+
+impl<I: Iterator<Item = u16>> Iterator for DecodeUtf16Unchecked<I> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        let unit = self.iter.next()?;
+        let code = if unit & 0xF800 != 0xD800 {
+            unit as u32
+        } else {
+            // Upheld by the caller of `decode_utf16_unchecked`.
+            unsafe {
+                assume(unit < 0xDC00);
+                let low = self.iter.next().unsafe_unwrap();
+                assume(low & 0xFC00 == 0xDC00);
+                0x10000 + (((unit as u32 & 0x3FF) << 10) | (low as u32 & 0x3FF))
+            }
+        };
+        Some(unsafe { char::from_u32_unchecked(code) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        (low.div_ceil(2), high)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_success() {
+        let s = unsafe { assume_ascii("hello, world") };
+        assert_eq!(s.slice(7..), "world");
+        assert_eq!(unsafe { s.slice_unchecked(..5) }, "hello");
+    }
+
+    #[test]
+    #[should_panic]
+    fn ascii_failure() {
+        unsafe { assume_ascii("caf\u{e9}") };
+    }
+
+    #[test]
+    fn char_boundary_success() {
+        let s = "caf\u{e9}!";
+        unsafe { assume_char_boundary(s, 5) };
+        assert_eq!(&s[5..], "!");
+    }
+
+    #[test]
+    #[should_panic]
+    fn char_boundary_failure() {
+        unsafe { assume_char_boundary("caf\u{e9}", 4) };
+    }
+
+    #[test]
+    fn digit_success() {
+        unsafe {
+            assert_eq!('z'.to_digit_unchecked(36), 35);
+            assert_eq!(char::from_digit_unchecked(11, 16), 'b');
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn to_digit_failure() {
+        unsafe { 'g'.to_digit_unchecked(16) };
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_digit_failure() {
+        unsafe { char::from_digit_unchecked(10, 10) };
+    }
+
+    #[test]
+    fn decode_utf16_success() {
+        let wide = [0x0063, 0x00E9, 0xD83D, 0xDE00, 0xFFFD];
+        let decoded = unsafe { decode_utf16_unchecked(wide.iter().cloned()) };
+        assert!(decoded.eq("c\u{e9}\u{1F600}\u{FFFD}".chars()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn decode_utf16_unpaired_high_failure() {
+        let wide = [0x0063, 0xD83D];
+        unsafe { decode_utf16_unchecked(wide.iter().cloned()).count() };
+    }
+
+    #[test]
+    #[should_panic]
+    fn decode_utf16_unpaired_low_failure() {
+        let wide = [0xDE00, 0x0063];
+        unsafe { decode_utf16_unchecked(wide.iter().cloned()).count() };
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn from_utf16_success() {
+        let wide = [0x0068, 0xD834, 0xDD1E, 0x0021];
+        assert_eq!(unsafe { from_utf16_unchecked(&wide) }, "h\u{1D11E}!");
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "alloc")]
+    fn from_utf16_failure() {
+        unsafe { from_utf16_unchecked(&[0xD834, 0x0021]) };
+    }
+}
@@ -0,0 +1,381 @@
+//! Unchecked atomic and lock operations.
+//!
+//! These are intended for protocols where an operation cannot fail by
+//! construction, such as a compare-exchange by the only writer.
+//!
+//! The lock traits are implemented for the standard locks with the `std`
+//! feature, and for `spin` locks with the `spin` feature.
+
+use core::sync::atomic::Ordering;
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::AtomicPtr;
+#[cfg(feature = "std")]
+use std::sync::{
+    Mutex, MutexGuard, OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard,
+};
+
+use UnsafeUnwrap;
+
+/// Atomic operations that assume the fallible ones succeed.
+pub trait UnsafeAtomic {
+    /// The type of the value held by the atomic.
+    type Value;
+
+    /// Stores `new` if the current value is `current`, assuming that it is,
+    /// and returns the previous value.
+    ///
+    /// # Safety
+    ///
+    /// `self.compare_exchange(current, new, success, failure)` must succeed.
+    /// This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use unsafe_unwrap::sync::UnsafeAtomic;
+    ///
+    /// // Only this thread writes `head`, so it cannot have changed.
+    /// let head = AtomicUsize::new(3);
+    /// let current = head.load(Ordering::Relaxed);
+    /// let prev = unsafe {
+    ///     head.compare_exchange_unchecked(
+    ///         current,
+    ///         current + 1,
+    ///         Ordering::Release,
+    ///         Ordering::Relaxed,
+    ///     )
+    /// };
+    /// assert_eq!(prev, 3);
+    /// ```
+    unsafe fn compare_exchange_unchecked(
+        &self,
+        current: Self::Value,
+        new: Self::Value,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Self::Value;
+
+    /// Updates the value with `f`, assuming that every call to `f` returns
+    /// `Some`, and returns the previous value.
+    ///
+    /// As with `fetch_update`, `f` may be called more than once if the value
+    /// is changed concurrently.
+    ///
+    /// # Safety
+    ///
+    /// `self.fetch_update(set_order, fetch_order, f)` must succeed. This is
+    /// checked in debug builds.
+    unsafe fn fetch_update_unchecked<F>(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        f: F,
+    ) -> Self::Value
+        where F: FnMut(Self::Value) -> Option<Self::Value>;
+}
+
+macro_rules! impl_unsafe_atomic {
+    ($($width:literal => $($atomic:ident($value:ty)),+;)+) => {
+        $($(
+            #[cfg(target_has_atomic = $width)]
+            impl UnsafeAtomic for core::sync::atomic::$atomic {
+                type Value = $value;
+
+                #[inline]
+                unsafe fn compare_exchange_unchecked(
+                    &self,
+                    current: $value,
+                    new: $value,
+                    success: Ordering,
+                    failure: Ordering,
+                ) -> $value {
+                    self.compare_exchange(current, new, success, failure)
+                        .unsafe_unwrap()
+                }
+
+                #[inline]
+                unsafe fn fetch_update_unchecked<F>(
+                    &self,
+                    set_order: Ordering,
+                    fetch_order: Ordering,
+                    f: F,
+                ) -> $value
+                    where F: FnMut($value) -> Option<$value>
+                {
+                    self.fetch_update(set_order, fetch_order, f).unsafe_unwrap()
+                }
+            }
+        )+)+
+    };
+}
+
+impl_unsafe_atomic! {
+    "8" => AtomicBool(bool), AtomicU8(u8), AtomicI8(i8);
+    "16" => AtomicU16(u16), AtomicI16(i16);
+    "32" => AtomicU32(u32), AtomicI32(i32);
+    "64" => AtomicU64(u64), AtomicI64(i64);
+    "ptr" => AtomicUsize(usize), AtomicIsize(isize);
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> UnsafeAtomic for AtomicPtr<T> {
+    type Value = *mut T;
+
+    #[inline]
+    unsafe fn compare_exchange_unchecked(
+        &self,
+        current: *mut T,
+        new: *mut T,
+        success: Ordering,
+        failure: Ordering,
+    ) -> *mut T {
+        self.compare_exchange(current, new, success, failure).unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn fetch_update_unchecked<F>(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        f: F,
+    ) -> *mut T
+        where F: FnMut(*mut T) -> Option<*mut T>
+    {
+        self.fetch_update(set_order, fetch_order, f).unsafe_unwrap()
+    }
+}
+
+/// A mutex that is known to be unlocked.
+pub trait UnsafeMutex {
+    /// The guard type of the mutex.
+    type Guard<'a> where Self: 'a;
+
+    /// Acquires the mutex without blocking, assuming that it is available.
+    ///
+    /// # Safety
+    ///
+    /// The mutex must not be locked, and for the standard `Mutex`, it must
+    /// not be poisoned. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")] {
+    /// use std::sync::Mutex;
+    /// use unsafe_unwrap::sync::UnsafeMutex;
+    ///
+    /// // Only the initializing thread has access to `config` so far.
+    /// let config = Mutex::new(Vec::new());
+    /// unsafe { config.try_lock_unchecked().push("verbose") };
+    /// # }
+    /// ```
+    unsafe fn try_lock_unchecked(&self) -> Self::Guard<'_>;
+}
+
+/// A reader-writer lock whose state is known to permit the access.
+pub trait UnsafeRwLock {
+    /// The shared guard type of the lock.
+    type ReadGuard<'a> where Self: 'a;
+
+    /// The exclusive guard type of the lock.
+    type WriteGuard<'a> where Self: 'a;
+
+    /// Acquires shared access without blocking, assuming that it is
+    /// available.
+    ///
+    /// # Safety
+    ///
+    /// `self.try_read()` must succeed: no writer may hold or be waiting on
+    /// the lock, and for the standard `RwLock`, it must not be poisoned. For
+    /// `spin` locks, no upgradeable guard may be held either. This is checked
+    /// in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")] {
+    /// use std::sync::RwLock;
+    /// use unsafe_unwrap::sync::UnsafeRwLock;
+    ///
+    /// // Writers only run during startup, before the lock is shared.
+    /// let routes = RwLock::new(vec!["/health"]);
+    /// assert_eq!(unsafe { routes.try_read_unchecked() }.len(), 1);
+    /// # }
+    /// ```
+    unsafe fn try_read_unchecked(&self) -> Self::ReadGuard<'_>;
+
+    /// Acquires exclusive access without blocking, assuming that it is
+    /// available.
+    ///
+    /// # Safety
+    ///
+    /// `self.try_write()` must succeed: the lock must not be held at all,
+    /// and for the standard `RwLock`, it must not be poisoned. This is
+    /// checked in debug builds.
+    unsafe fn try_write_unchecked(&self) -> Self::WriteGuard<'_>;
+}
+
+/// A once-initialized cell that is known to be initialized.
+pub trait UnsafeOnce {
+    /// The type of the value held by the cell.
+    type Value;
+
+    /// Returns the value, assuming that the cell is initialized.
+    ///
+    /// # Safety
+    ///
+    /// The cell must be initialized. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")] {
+    /// use std::sync::OnceLock;
+    /// use unsafe_unwrap::sync::UnsafeOnce;
+    ///
+    /// static LEVEL: OnceLock<u8> = OnceLock::new();
+    ///
+    /// // Set during startup, before any reader runs.
+    /// LEVEL.set(3).unwrap();
+    /// assert_eq!(unsafe { *LEVEL.get_init_unchecked() }, 3);
+    /// # }
+    /// ```
+    unsafe fn get_init_unchecked(&self) -> &Self::Value;
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized> UnsafeMutex for Mutex<T> {
+    type Guard<'a> = MutexGuard<'a, T> where Self: 'a;
+
+    #[inline]
+    unsafe fn try_lock_unchecked(&self) -> MutexGuard<'_, T> {
+        self.try_lock().unsafe_unwrap()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized> UnsafeRwLock for RwLock<T> {
+    type ReadGuard<'a> = RwLockReadGuard<'a, T> where Self: 'a;
+    type WriteGuard<'a> = RwLockWriteGuard<'a, T> where Self: 'a;
+
+    #[inline]
+    unsafe fn try_read_unchecked(&self) -> RwLockReadGuard<'_, T> {
+        self.try_read().unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn try_write_unchecked(&self) -> RwLockWriteGuard<'_, T> {
+        self.try_write().unsafe_unwrap()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> UnsafeOnce for OnceLock<T> {
+    type Value = T;
+
+    #[inline]
+    unsafe fn get_init_unchecked(&self) -> &T {
+        self.get().unsafe_unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::AtomicU32;
+
+    #[test]
+    fn compare_exchange_success() {
+        let x = AtomicU32::new(1);
+        let prev = unsafe {
+            x.compare_exchange_unchecked(1, 2, Ordering::AcqRel, Ordering::Relaxed)
+        };
+        assert_eq!((prev, x.load(Ordering::Relaxed)), (1, 2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn compare_exchange_failure() {
+        let x = AtomicU32::new(1);
+        unsafe {
+            x.compare_exchange_unchecked(0, 2, Ordering::AcqRel, Ordering::Relaxed)
+        };
+    }
+
+    #[test]
+    fn fetch_update_success() {
+        let mut value = 0u8;
+        let ptr = AtomicPtr::new(core::ptr::null_mut::<u8>());
+        let prev = unsafe {
+            ptr.fetch_update_unchecked(Ordering::AcqRel, Ordering::Acquire, |p| {
+                if p.is_null() { Some(&mut value as *mut u8) } else { None }
+            })
+        };
+        assert!(prev.is_null());
+        assert_eq!(ptr.load(Ordering::Relaxed), &mut value as *mut u8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fetch_update_failure() {
+        let x = AtomicU32::new(u32::MAX);
+        unsafe {
+            x.fetch_update_unchecked(Ordering::AcqRel, Ordering::Acquire, |x| {
+                x.checked_add(1)
+            })
+        };
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn mutex_success() {
+        let mutex = Mutex::new(1);
+        unsafe { *mutex.try_lock_unchecked() += 1 };
+        assert_eq!(*mutex.lock().unwrap(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "std")]
+    fn mutex_failure() {
+        let mutex = Mutex::new(1);
+        let _guard = mutex.lock().unwrap();
+        drop(unsafe { mutex.try_lock_unchecked() });
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn rw_lock_success() {
+        let lock = RwLock::new(1);
+        unsafe {
+            *lock.try_write_unchecked() += 1;
+            let _first = lock.try_read_unchecked();
+            assert_eq!(*lock.try_read_unchecked(), 2);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "std")]
+    fn rw_lock_failure() {
+        let lock = RwLock::new(1);
+        let _guard = lock.read().unwrap();
+        drop(unsafe { lock.try_write_unchecked() });
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn once_lock_success() {
+        let cell = OnceLock::new();
+        cell.set(1).unwrap();
+        assert_eq!(unsafe { *cell.get_init_unchecked() }, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "std")]
+    fn once_lock_failure() {
+        unsafe { OnceLock::<u8>::new().get_init_unchecked() };
+    }
+}
@@ -0,0 +1,152 @@
+//! Unchecked access to thread-local values.
+//!
+//! These are intended for per-thread state on hot paths, such as arenas,
+//! that is never accessed during thread teardown. This module requires the
+//! `std` feature.
+
+use core::cell::RefCell;
+use std::thread::LocalKey;
+
+use UnsafeUnwrap;
+
+/// Thread-local access that assumes the value has not been destroyed.
+pub trait UnsafeLocalKey<T: 'static> {
+    /// Calls `f` with the thread-local value, assuming that it has not been
+    /// destroyed.
+    ///
+    /// Unlike `with`, this has no `AccessError` branch in optimized builds.
+    ///
+    /// # Safety
+    ///
+    /// `self.try_with(f)` must be `Ok`, which it is outside of thread-local
+    /// destructors. This is checked in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::cell::Cell;
+    /// use unsafe_unwrap::thread::UnsafeLocalKey;
+    ///
+    /// thread_local!(static ALLOCATED: Cell<usize> = Cell::new(0));
+    ///
+    /// unsafe { ALLOCATED.try_with_unchecked(|n| n.set(n.get() + 64)) };
+    /// assert_eq!(ALLOCATED.with(Cell::get), 64);
+    /// ```
+    unsafe fn try_with_unchecked<F, R>(&'static self, f: F) -> R
+        where F: FnOnce(&T) -> R;
+}
+
+impl<T: 'static> UnsafeLocalKey<T> for LocalKey<T> {
+    #[inline]
+    unsafe fn try_with_unchecked<F, R>(&'static self, f: F) -> R
+        where F: FnOnce(&T) -> R
+    {
+        self.try_with(f).unsafe_unwrap()
+    }
+}
+
+/// Access to a thread-local `RefCell` that assumes the value is alive and
+/// not already borrowed.
+pub trait UnsafeLocalRefCell<T: 'static> {
+    /// Calls `f` with a shared borrow of the thread-local value, assuming
+    /// that the borrow succeeds.
+    ///
+    /// # Safety
+    ///
+    /// The value must not have been destroyed or be mutably borrowed. This is
+    /// checked in debug builds.
+    unsafe fn with_borrow_unchecked<F, R>(&'static self, f: F) -> R
+        where F: FnOnce(&T) -> R;
+
+    /// Calls `f` with a mutable borrow of the thread-local value, assuming
+    /// that the borrow succeeds.
+    ///
+    /// # Safety
+    ///
+    /// The value must not have been destroyed or be borrowed. This is checked
+    /// in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::cell::RefCell;
+    /// use unsafe_unwrap::thread::UnsafeLocalRefCell;
+    ///
+    /// thread_local! {
+    ///     static ARENA: RefCell<Vec<u8>> = RefCell::new(Vec::new());
+    /// }
+    ///
+    /// // The arena is never borrowed across calls.
+    /// let offset = unsafe {
+    ///     ARENA.with_borrow_mut_unchecked(|arena| {
+    ///         arena.extend_from_slice(&[0; 16]);
+    ///         arena.len() - 16
+    ///     })
+    /// };
+    /// assert_eq!(offset, 0);
+    /// ```
+    unsafe fn with_borrow_mut_unchecked<F, R>(&'static self, f: F) -> R
+        where F: FnOnce(&mut T) -> R;
+}
+
+impl<T: 'static> UnsafeLocalRefCell<T> for LocalKey<RefCell<T>> {
+    #[inline]
+    unsafe fn with_borrow_unchecked<F, R>(&'static self, f: F) -> R
+        where F: FnOnce(&T) -> R
+    {
+        self.try_with_unchecked(|cell| f(&cell.try_borrow().unsafe_unwrap()))
+    }
+
+    #[inline]
+    unsafe fn with_borrow_mut_unchecked<F, R>(&'static self, f: F) -> R
+        where F: FnOnce(&mut T) -> R
+    {
+        self.try_with_unchecked(|cell| {
+            f(&mut cell.try_borrow_mut().unsafe_unwrap())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+    use std::thread_local;
+
+    thread_local! {
+        static COUNT: Cell<u32> = const { Cell::new(0) };
+        static CELL: RefCell<u32> = const { RefCell::new(0) };
+    }
+
+    #[test]
+    fn try_with_success() {
+        unsafe { COUNT.try_with_unchecked(|c| c.set(1)) };
+        assert_eq!(COUNT.with(Cell::get), 1);
+    }
+
+    #[test]
+    fn with_borrow_success() {
+        unsafe {
+            CELL.with_borrow_mut_unchecked(|x| *x += 1);
+            assert_eq!(CELL.with_borrow_unchecked(|x| *x), 1);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_borrow_mut_failure() {
+        CELL.with(|cell| {
+            let _borrow = cell.borrow();
+            unsafe { CELL.with_borrow_mut_unchecked(|_| ()) };
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_borrow_failure() {
+        CELL.with(|cell| {
+            let _borrow = cell.borrow_mut();
+            unsafe { CELL.with_borrow_unchecked(|_| ()) };
+        });
+    }
+}
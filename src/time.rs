@@ -0,0 +1,205 @@
+//! Unchecked time arithmetic.
+//!
+//! The `Instant` and `SystemTime` extensions require the `std` feature.
+
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::{Instant, SystemTime};
+
+use UnsafeUnwrap;
+
+/// Arithmetic on `Duration` that assumes the checked operations succeed.
+pub trait UnsafeDuration: Sized {
+    /// Adds `rhs` to `self`, assuming the result does not overflow.
+    ///
+    /// # Safety
+    ///
+    /// `self.checked_add(rhs)` must be `Some`. This is checked in debug
+    /// builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use unsafe_unwrap::time::UnsafeDuration;
+    ///
+    /// let frame = Duration::from_millis(16);
+    /// let elapsed = unsafe { frame.add_unchecked(frame) };
+    /// assert_eq!(elapsed, Duration::from_millis(32));
+    /// ```
+    unsafe fn add_unchecked(self, rhs: Duration) -> Self;
+
+    /// Subtracts `rhs` from `self`, assuming the result is not negative.
+    ///
+    /// # Safety
+    ///
+    /// `self.checked_sub(rhs)` must be `Some`. This is checked in debug
+    /// builds.
+    unsafe fn sub_unchecked(self, rhs: Duration) -> Self;
+
+    /// Multiplies `self` by `rhs`, assuming the result does not overflow.
+    ///
+    /// # Safety
+    ///
+    /// `self.checked_mul(rhs)` must be `Some`. This is checked in debug
+    /// builds.
+    unsafe fn mul_unchecked(self, rhs: u32) -> Self;
+}
+
+impl UnsafeDuration for Duration {
+    #[inline]
+    unsafe fn add_unchecked(self, rhs: Duration) -> Self {
+        self.checked_add(rhs).unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn sub_unchecked(self, rhs: Duration) -> Self {
+        self.checked_sub(rhs).unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn mul_unchecked(self, rhs: u32) -> Self {
+        self.checked_mul(rhs).unsafe_unwrap()
+    }
+}
+
+/// Arithmetic on `Instant` that assumes the checked operations succeed.
+#[cfg(feature = "std")]
+pub trait UnsafeInstant: Sized {
+    /// Adds `duration` to `self`, assuming the result is representable.
+    ///
+    /// # Safety
+    ///
+    /// `self.checked_add(duration)` must be `Some`. This is checked in debug
+    /// builds.
+    unsafe fn add_unchecked(self, duration: Duration) -> Self;
+
+    /// Returns the time elapsed from `earlier` to `self`, assuming `earlier`
+    /// is not later than `self`.
+    ///
+    /// # Safety
+    ///
+    /// `self.checked_duration_since(earlier)` must be `Some`. This is checked
+    /// in debug builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Instant;
+    /// use unsafe_unwrap::time::UnsafeInstant;
+    ///
+    /// let start = Instant::now();
+    /// let tick = Instant::now();
+    /// let dt = unsafe { tick.duration_since_unchecked(start) };
+    /// ```
+    unsafe fn duration_since_unchecked(&self, earlier: Self) -> Duration;
+}
+
+#[cfg(feature = "std")]
+impl UnsafeInstant for Instant {
+    #[inline]
+    unsafe fn add_unchecked(self, duration: Duration) -> Self {
+        self.checked_add(duration).unsafe_unwrap()
+    }
+
+    #[inline]
+    unsafe fn duration_since_unchecked(&self, earlier: Self) -> Duration {
+        self.checked_duration_since(earlier).unsafe_unwrap()
+    }
+}
+
+/// Arithmetic on `SystemTime` that assumes the clock does not go backwards.
+#[cfg(feature = "std")]
+pub trait UnsafeSystemTime {
+    /// Returns the time elapsed from `earlier` to `self`, assuming `earlier`
+    /// is not later than `self`.
+    ///
+    /// Unlike `Instant`, the system clock may be adjusted backwards, so this
+    /// is only sound where such a regression is ruled out.
+    ///
+    /// # Safety
+    ///
+    /// `self.duration_since(earlier)` must be `Ok`. This is checked in debug
+    /// builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::{Duration, SystemTime};
+    /// use unsafe_unwrap::time::UnsafeSystemTime;
+    ///
+    /// let sent = SystemTime::now();
+    /// let received = sent + Duration::from_millis(5);
+    /// let latency = unsafe { received.duration_since_unchecked(sent) };
+    /// assert_eq!(latency, Duration::from_millis(5));
+    /// ```
+    unsafe fn duration_since_unchecked(&self, earlier: SystemTime) -> Duration;
+}
+
+#[cfg(feature = "std")]
+impl UnsafeSystemTime for SystemTime {
+    #[inline]
+    unsafe fn duration_since_unchecked(&self, earlier: SystemTime) -> Duration {
+        self.duration_since(earlier).unsafe_unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_success() {
+        let second = Duration::from_secs(1);
+        unsafe {
+            assert_eq!(second.add_unchecked(second), second * 2);
+            assert_eq!(second.sub_unchecked(second), Duration::from_secs(0));
+            assert_eq!(second.mul_unchecked(3), second * 3);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn duration_failure() {
+        let second = Duration::from_secs(1);
+        unsafe { Duration::from_secs(0).sub_unchecked(second) };
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn instant_success() {
+        let start = Instant::now();
+        unsafe {
+            let later = start.add_unchecked(Duration::from_secs(1));
+            let dt = later.duration_since_unchecked(start);
+            assert_eq!(dt, Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "std")]
+    fn instant_failure() {
+        let start = Instant::now();
+        let later = start + Duration::from_secs(1);
+        unsafe { start.duration_since_unchecked(later) };
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn system_time_success() {
+        let now = SystemTime::now();
+        let later = now + Duration::from_secs(1);
+        let dt = unsafe { later.duration_since_unchecked(now) };
+        assert_eq!(dt, Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "std")]
+    fn system_time_failure() {
+        let now = SystemTime::now();
+        let later = now + Duration::from_secs(1);
+        unsafe { now.duration_since_unchecked(later) };
+    }
+}
@@ -0,0 +1,41 @@
+//! Verifies that debug-build failures are routed to the user-defined hook with
+//! the location of the failing call.
+//!
+//! Run with `cargo test --features failure-hook --test failure_hook`.
+
+#![cfg(all(feature = "failure-hook", debug_assertions))]
+
+// `unsafe_unwrap()` is exercised even when the `strict` feature is enabled.
+#![allow(deprecated)]
+
+extern crate unsafe_unwrap;
+
+use std::panic;
+
+use unsafe_unwrap::UnsafeUnwrap;
+
+#[no_mangle]
+fn unsafe_unwrap_failed(file: &str, line: u32) -> ! {
+    panic::panic_any((file.to_owned(), line))
+}
+
+fn failure<F: FnOnce() + panic::UnwindSafe>(f: F) -> (String, u32) {
+    let payload = panic::catch_unwind(f).unwrap_err();
+    *payload.downcast::<(String, u32)>().unwrap()
+}
+
+#[test]
+fn option_reports_caller() {
+    let (file, line) = failure(|| unsafe { None::<u8>.unsafe_unwrap(); });
+    assert!(file.ends_with("failure_hook.rs"));
+    assert_eq!(line, line!() - 2);
+}
+
+#[test]
+fn result_because_reports_caller() {
+    let (file, line) = failure(|| unsafe {
+        Err::<u8, ()>(()).unsafe_unwrap_because("never");
+    });
+    assert!(file.ends_with("failure_hook.rs"));
+    assert_eq!(line, line!() - 3);
+}
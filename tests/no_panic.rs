@@ -0,0 +1,108 @@
+//! Verifies that representative callers contain no reachable panics in
+//! optimized builds.
+//!
+//! Each function below holds a guard whose destructor references an undefined
+//! symbol. The destructor only runs if the function unwinds, so linking fails
+//! unless the optimizer removed every panic from the call path.
+//!
+//! Run with `cargo test --release --features no-panic-guarantee --test no_panic`.
+//! It is skipped when checks may run in optimized builds, as under `verify` or
+//! `runtime-policy`.
+
+#![cfg(all(
+    feature = "no-panic-guarantee",
+    not(any(debug_assertions, feature = "verify", feature = "runtime-policy")),
+))]
+
+// `unsafe_unwrap()` is exercised even when the `strict` feature is enabled.
+#![allow(deprecated)]
+
+#[macro_use]
+extern crate unsafe_unwrap;
+
+use std::hint::black_box;
+use std::mem;
+
+use unsafe_unwrap::slice::UnsafeSlice;
+use unsafe_unwrap::{UnsafeUnwrap, UnsafeUnwrapDeep, UnsafeUnwrapExt};
+
+struct Guard;
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        extern "C" {
+            #[link_name = "\n\nERROR: an `unsafe_unwrap` call path may panic\n\n"]
+            fn may_panic() -> !;
+        }
+        unsafe { may_panic() }
+    }
+}
+
+macro_rules! no_panic {
+    ($(fn $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty $body:block)+) => {
+        $(
+            #[inline(never)]
+            fn $name($($arg: $ty),*) -> $ret {
+                let guard = Guard;
+                let ret = $body;
+                mem::forget(guard);
+                ret
+            }
+        )+
+    };
+}
+
+no_panic! {
+    fn option(x: Option<u32>) -> u32 {
+        unsafe { x.unsafe_unwrap() }
+    }
+
+    fn result(x: Result<String, String>) -> String {
+        unsafe { x.unsafe_unwrap() }
+    }
+
+    fn by_ref(x: &Option<Vec<u8>>) -> usize {
+        unsafe { x.unsafe_unwrap_ref().len() }
+    }
+
+    fn deep(x: Result<Option<u64>, ()>) -> u64 {
+        unsafe { x.unsafe_unwrap_deep() }
+    }
+
+    fn array(x: [Option<u16>; 4]) -> [u16; 4] {
+        unsafe { x.unsafe_unwrap() }
+    }
+
+    fn field(x: &[u8]) -> u32 {
+        u32::from_le_bytes(unsafe { *x.as_array_unchecked::<4>() })
+    }
+
+    fn copy(dst: &mut [u8], src: &[u8]) -> usize {
+        unsafe { dst.copy_from_slice_unchecked(src) };
+        dst.len()
+    }
+
+    fn arm(x: u8) -> u8 {
+        match x % 3 {
+            0 => 10,
+            1 => 20,
+            2 => 30,
+            other => unsafe { debug_unreachable!("remainder {}", other) },
+        }
+    }
+}
+
+#[test]
+fn no_panic() {
+    assert_eq!(option(black_box(Some(1))), 1);
+    assert_eq!(result(black_box(Ok("ok".to_owned()))), "ok");
+    assert_eq!(by_ref(black_box(&Some(vec![0; 3]))), 3);
+    assert_eq!(deep(black_box(Ok(Some(2)))), 2);
+    assert_eq!(array(black_box([Some(1); 4])), [1; 4]);
+    assert_eq!(field(black_box(&[1, 0, 0, 0])), 1);
+    assert_eq!(arm(black_box(5)), 30);
+
+    let mut dst = [0; 2];
+    assert_eq!(copy(&mut dst, black_box(&[1, 2])), 2);
+    assert_eq!(dst, [1, 2]);
+}
@@ -0,0 +1,29 @@
+//! Verifies that `UNSAFE_UNWRAP_CHECKS=1` makes optimized-build failures
+//! panic.
+//!
+//! Run with `cargo test --release --features runtime-policy --test
+//! runtime_policy`.
+
+#![cfg(all(
+    feature = "runtime-policy",
+    not(feature = "failure-hook"),
+    not(debug_assertions),
+))]
+
+// `unsafe_unwrap()` is exercised even when the `strict` feature is enabled.
+#![allow(deprecated)]
+
+extern crate unsafe_unwrap;
+
+use std::hint::black_box;
+use std::{env, panic};
+
+use unsafe_unwrap::UnsafeUnwrap;
+
+#[test]
+fn env_enables_checks() {
+    // The variable is read on the first failure, so only one test may fail.
+    env::set_var("UNSAFE_UNWRAP_CHECKS", "1");
+    let x = black_box(None::<u8>);
+    assert!(panic::catch_unwind(|| unsafe { x.unsafe_unwrap() }).is_err());
+}